@@ -0,0 +1,49 @@
+//! Locates cairo and the enabled backends with pkg-config.
+
+extern crate "pkg-config" as pkg_config;
+
+use std::os;
+
+/// The oldest supported cairo release, which has all the functions declared by this crate outside the version features.
+static MINIMUM_VERSION: &'static str = "1.12";
+
+/// Features declaring functions of newer releases, as the Cargo feature and the release, from oldest to newest.
+static VERSIONS: &'static [(&'static str, &'static str)] = &[
+  ("V1_14", "1.14"),
+  ("V1_16", "1.16"),
+  ("V1_18", "1.18")
+];
+
+/// Optional backends, as the Cargo feature and the pkg-config package providing them.
+static BACKENDS: &'static [(&'static str, &'static str)] = &[
+  ("PNG", "cairo-png"),
+  ("SVG", "cairo-svg"),
+  ("PDF", "cairo-pdf"),
+  ("PS", "cairo-ps"),
+  ("FT", "cairo-ft"),
+  ("SCRIPT", "cairo-script"),
+  ("TEE", "cairo-tee")
+];
+
+fn main() {
+  let mut minimum_version = MINIMUM_VERSION;
+  for &(feature, version) in VERSIONS.iter() {
+    if os::getenv(format!("CARGO_FEATURE_{}", feature).as_slice()).is_some() {
+      minimum_version = version;
+    }
+  }
+  match pkg_config::Config::new().atleast_version(minimum_version).find("cairo") {
+    Ok(_) => {},
+    Err(error) => panic!("cairo {} or newer is required: {}", minimum_version, error)
+  }
+  for &(feature, package) in BACKENDS.iter() {
+    if os::getenv(format!("CARGO_FEATURE_{}", feature).as_slice()).is_none() {
+      continue;
+    }
+    // The backends are built into libcairo, so this only checks that the installed cairo was configured with them.
+    match pkg_config::Config::new().atleast_version(minimum_version).find(package) {
+      Ok(_) => {},
+      Err(error) => panic!("the {} backend needs {}: {}", feature, package, error)
+    }
+  }
+}
@@ -0,0 +1,564 @@
+#![crate_name = "cairo-sys"]
+#![crate_type = "lib"]
+#![allow(non_camel_case_types)]
+
+//! Raw declarations of the Cairo C API.
+//!
+//! Linking is set up by the build script, which locates cairo and the optional backends with pkg-config. The safe bindings live in the cairo crate.
+
+extern crate libc;
+
+use libc::{c_char, c_double, c_int, c_uchar, c_uint, c_ulong, c_void};
+
+pub type cairo_bool_t = c_int;
+
+pub type cairo_t = c_void;
+pub type cairo_pattern_t = c_void;
+pub type cairo_surface_t = c_void;
+pub type cairo_device_t = c_void;
+pub type cairo_region_t = c_void;
+pub type cairo_font_options_t = c_void;
+pub type cairo_font_face_t = c_void;
+pub type cairo_scaled_font_t = c_void;
+
+pub type cairo_status_t = c_int;
+pub type cairo_content_t = c_int;
+pub type cairo_format_t = c_int;
+pub type cairo_operator_t = c_int;
+pub type cairo_antialias_t = c_int;
+pub type cairo_fill_rule_t = c_int;
+pub type cairo_line_cap_t = c_int;
+pub type cairo_line_join_t = c_int;
+pub type cairo_text_cluster_flags_t = c_int;
+pub type cairo_font_slant_t = c_int;
+pub type cairo_font_weight_t = c_int;
+pub type cairo_subpixel_order_t = c_int;
+pub type cairo_hint_style_t = c_int;
+pub type cairo_hint_metrics_t = c_int;
+pub type cairo_font_type_t = c_int;
+pub type cairo_path_data_type_t = c_int;
+pub type cairo_device_type_t = c_int;
+pub type cairo_surface_type_t = c_int;
+pub type cairo_pattern_type_t = c_int;
+pub type cairo_extend_t = c_int;
+pub type cairo_filter_t = c_int;
+pub type cairo_dither_t = c_int;
+pub type cairo_region_overlap_t = c_int;
+pub type cairo_svg_version_t = c_int;
+
+#[repr(C)]
+pub struct cairo_matrix_t {
+  pub xx: c_double,
+  pub yx: c_double,
+  pub xy: c_double,
+  pub yy: c_double,
+  pub x0: c_double,
+  pub y0: c_double
+}
+
+#[repr(C)]
+pub struct cairo_rectangle_t {
+  pub x: c_double,
+  pub y: c_double,
+  pub width: c_double,
+  pub height: c_double
+}
+
+#[repr(C)]
+pub struct cairo_rectangle_list_t {
+  pub status: cairo_status_t,
+  pub rectangles: *mut cairo_rectangle_t,
+  pub num_rectangles: c_int
+}
+
+#[repr(C)]
+pub struct cairo_rectangle_int_t {
+  pub x: c_int,
+  pub y: c_int,
+  pub width: c_int,
+  pub height: c_int
+}
+
+#[repr(C)]
+pub struct cairo_glyph_t {
+  pub index: c_ulong,
+  pub x: c_double,
+  pub y: c_double
+}
+
+#[repr(C)]
+pub struct cairo_text_cluster_t {
+  pub num_bytes: c_int,
+  pub num_glyphs: c_int
+}
+
+#[repr(C)]
+pub struct cairo_font_extents_t {
+  pub ascent: c_double,
+  pub descent: c_double,
+  pub height: c_double,
+  pub max_x_advance: c_double,
+  pub max_y_advance: c_double
+}
+
+#[repr(C)]
+pub struct cairo_text_extents_t {
+  pub x_bearing: c_double,
+  pub y_bearing: c_double,
+  pub width: c_double,
+  pub height: c_double,
+  pub x_advance: c_double,
+  pub y_advance: c_double
+}
+
+/// The point view of the cairo_path_data_t union. The header view, a cairo_path_data_type_t followed by the number of elements, overlaps x.
+#[repr(C)]
+pub struct cairo_path_data_t {
+  pub x: c_double,
+  pub y: c_double
+}
+
+#[repr(C)]
+pub struct cairo_path_t {
+  pub status: cairo_status_t,
+  pub data: *mut cairo_path_data_t,
+  pub num_data: c_int
+}
+
+pub type cairo_script_mode_t = c_int;
+pub type cairo_surface_observer_mode_t = c_int;
+
+#[repr(C)]
+pub struct cairo_user_data_key_t {
+  pub unused: c_int
+}
+
+pub type cairo_destroy_func_t = extern "C" fn(data: *mut c_void);
+pub type cairo_write_func_t = extern "C" fn(closure: *mut c_void, data: *c_uchar, length: c_uint) -> cairo_status_t;
+
+pub type cairo_surface_observer_callback_t = extern "C" fn(observer: *mut cairo_surface_t, target: *mut cairo_surface_t, data: *mut c_void);
+
+pub type cairo_raster_source_acquire_func_t = extern "C" fn(pattern: *mut cairo_pattern_t, callback_data: *mut c_void, target: *mut cairo_surface_t, extents: *cairo_rectangle_int_t) -> *mut cairo_surface_t;
+pub type cairo_raster_source_release_func_t = extern "C" fn(pattern: *mut cairo_pattern_t, callback_data: *mut c_void, surface: *mut cairo_surface_t);
+pub type cairo_raster_source_copy_func_t = extern "C" fn(pattern: *mut cairo_pattern_t, callback_data: *mut c_void, other: *cairo_pattern_t) -> cairo_status_t;
+pub type cairo_raster_source_finish_func_t = extern "C" fn(pattern: *mut cairo_pattern_t, callback_data: *mut c_void);
+
+// Versions
+extern "C" {
+  pub fn cairo_version() -> c_int;
+  pub fn cairo_version_string() -> *c_char;
+}
+
+// Drawing context
+extern "C" {
+  pub fn cairo_create(target: *mut cairo_surface_t) -> *mut cairo_t;
+  pub fn cairo_status(cr: *mut cairo_t) -> cairo_status_t;
+  pub fn cairo_save(cr: *mut cairo_t);
+  pub fn cairo_restore(cr: *mut cairo_t);
+  pub fn cairo_get_target(cr: *mut cairo_t) -> *mut cairo_surface_t;
+  pub fn cairo_push_group(cr: *mut cairo_t);
+  pub fn cairo_push_group_with_content(cr: *mut cairo_t, content: cairo_content_t);
+  pub fn cairo_pop_group(cr: *mut cairo_t) -> *mut cairo_pattern_t;
+  pub fn cairo_pop_group_to_source(cr: *mut cairo_t);
+  pub fn cairo_get_group_target(cr: *mut cairo_t) -> *mut cairo_surface_t;
+  pub fn cairo_set_source_rgb(cr: *mut cairo_t, red: c_double, green: c_double, blue: c_double);
+  pub fn cairo_set_source_rgba(cr: *mut cairo_t, red: c_double, green: c_double, blue: c_double, alpha: c_double);
+  pub fn cairo_set_source(cr: *mut cairo_t, source: *mut cairo_pattern_t);
+  pub fn cairo_set_source_surface(cr: *mut cairo_t, surface: *mut cairo_surface_t, x: c_double, y: c_double);
+  pub fn cairo_get_source(cr: *mut cairo_t) -> *mut cairo_pattern_t;
+  pub fn cairo_set_antialias(cr: *mut cairo_t, antialias: cairo_antialias_t);
+  pub fn cairo_get_antialias(cr: *mut cairo_t) -> cairo_antialias_t;
+  pub fn cairo_set_dash(cr: *mut cairo_t, dashes: *c_double, num_dashes: c_int, offset: c_double);
+  pub fn cairo_get_dash_count(cr: *mut cairo_t) -> c_int;
+  pub fn cairo_get_dash(cr: *mut cairo_t, dashes: *mut c_double, offset: *mut c_double);
+  pub fn cairo_set_fill_rule(cr: *mut cairo_t, fill_rule: cairo_fill_rule_t);
+  pub fn cairo_get_fill_rule(cr: *mut cairo_t) -> cairo_fill_rule_t;
+  pub fn cairo_set_line_cap(cr: *mut cairo_t, line_cap: cairo_line_cap_t);
+  pub fn cairo_get_line_cap(cr: *mut cairo_t) -> cairo_line_cap_t;
+  pub fn cairo_set_line_join(cr: *mut cairo_t, line_join: cairo_line_join_t);
+  pub fn cairo_get_line_join(cr: *mut cairo_t) -> cairo_line_join_t;
+  pub fn cairo_set_line_width(cr: *mut cairo_t, width: c_double);
+  pub fn cairo_get_line_width(cr: *mut cairo_t) -> c_double;
+  #[cfg(feature = "v1_18")]
+  pub fn cairo_set_hairline(cr: *mut cairo_t, set_hairline: cairo_bool_t);
+  #[cfg(feature = "v1_18")]
+  pub fn cairo_get_hairline(cr: *mut cairo_t) -> cairo_bool_t;
+  pub fn cairo_set_miter_limit(cr: *mut cairo_t, limit: c_double);
+  pub fn cairo_get_miter_limit(cr: *mut cairo_t) -> c_double;
+  pub fn cairo_set_operator(cr: *mut cairo_t, operator: cairo_operator_t);
+  pub fn cairo_get_operator(cr: *mut cairo_t) -> cairo_operator_t;
+  pub fn cairo_set_tolerance(cr: *mut cairo_t, tolerance: c_double);
+  pub fn cairo_get_tolerance(cr: *mut cairo_t) -> c_double;
+  pub fn cairo_clip(cr: *mut cairo_t);
+  pub fn cairo_clip_preserve(cr: *mut cairo_t);
+  pub fn cairo_clip_extents(cr: *mut cairo_t, x1: *mut c_double, y1: *mut c_double, x2: *mut c_double, y2: *mut c_double);
+  pub fn cairo_copy_clip_rectangle_list(cr: *mut cairo_t) -> *mut cairo_rectangle_list_t;
+  pub fn cairo_rectangle_list_destroy(rectangle_list: *mut cairo_rectangle_list_t);
+  pub fn cairo_in_clip(cr: *mut cairo_t, x: c_double, y: c_double) -> cairo_bool_t;
+  pub fn cairo_reset_clip(cr: *mut cairo_t);
+  pub fn cairo_fill(cr: *mut cairo_t);
+  pub fn cairo_fill_preserve(cr: *mut cairo_t);
+  pub fn cairo_fill_extents(cr: *mut cairo_t, x1: *mut c_double, y1: *mut c_double, x2: *mut c_double, y2: *mut c_double);
+  pub fn cairo_in_fill(cr: *mut cairo_t, x: c_double, y: c_double) -> cairo_bool_t;
+  pub fn cairo_mask(cr: *mut cairo_t, pattern: *mut cairo_pattern_t);
+  pub fn cairo_mask_surface(cr: *mut cairo_t, surface: *mut cairo_surface_t, surface_x: c_double, surface_y: c_double);
+  pub fn cairo_paint(cr: *mut cairo_t);
+  pub fn cairo_paint_with_alpha(cr: *mut cairo_t, alpha: c_double);
+  pub fn cairo_stroke(cr: *mut cairo_t);
+  pub fn cairo_stroke_preserve(cr: *mut cairo_t);
+  pub fn cairo_stroke_extents(cr: *mut cairo_t, x1: *mut c_double, y1: *mut c_double, x2: *mut c_double, y2: *mut c_double);
+  pub fn cairo_in_stroke(cr: *mut cairo_t, x: c_double, y: c_double) -> cairo_bool_t;
+  pub fn cairo_copy_page(cr: *mut cairo_t);
+  pub fn cairo_show_page(cr: *mut cairo_t);
+  pub fn cairo_get_reference_count(cr: *mut cairo_t) -> c_uint;
+  pub fn cairo_set_user_data(cr: *mut cairo_t, key: *cairo_user_data_key_t, user_data: *mut c_void, destroy: cairo_destroy_func_t) -> cairo_status_t;
+  pub fn cairo_get_user_data(cr: *mut cairo_t, key: *cairo_user_data_key_t) -> *mut c_void;
+  pub fn cairo_reference(cr: *mut cairo_t) -> *mut cairo_t;
+  pub fn cairo_destroy(cr: *mut cairo_t);
+}
+
+// Tags
+#[cfg(feature = "v1_16")]
+extern "C" {
+  pub fn cairo_tag_begin(cr: *mut cairo_t, tag_name: *c_char, attributes: *c_char);
+  pub fn cairo_tag_end(cr: *mut cairo_t, tag_name: *c_char);
+}
+
+// Paths
+extern "C" {
+  pub fn cairo_copy_path(cr: *mut cairo_t) -> *mut cairo_path_t;
+  pub fn cairo_copy_path_flat(cr: *mut cairo_t) -> *mut cairo_path_t;
+  pub fn cairo_append_path(cr: *mut cairo_t, path: *cairo_path_t);
+  pub fn cairo_has_current_point(cr: *mut cairo_t) -> cairo_bool_t;
+  pub fn cairo_get_current_point(cr: *mut cairo_t, x: *mut c_double, y: *mut c_double);
+  pub fn cairo_new_path(cr: *mut cairo_t);
+  pub fn cairo_new_sub_path(cr: *mut cairo_t);
+  pub fn cairo_close_path(cr: *mut cairo_t);
+  pub fn cairo_arc(cr: *mut cairo_t, xc: c_double, yc: c_double, radius: c_double, angle1: c_double, angle2: c_double);
+  pub fn cairo_arc_negative(cr: *mut cairo_t, xc: c_double, yc: c_double, radius: c_double, angle1: c_double, angle2: c_double);
+  pub fn cairo_curve_to(cr: *mut cairo_t, x1: c_double, y1: c_double, x2: c_double, y2: c_double, x3: c_double, y3: c_double);
+  pub fn cairo_line_to(cr: *mut cairo_t, x: c_double, y: c_double);
+  pub fn cairo_move_to(cr: *mut cairo_t, x: c_double, y: c_double);
+  pub fn cairo_rectangle(cr: *mut cairo_t, x: c_double, y: c_double, width: c_double, height: c_double);
+  pub fn cairo_glyph_path(cr: *mut cairo_t, glyphs: *cairo_glyph_t, num_glyphs: c_int);
+  pub fn cairo_text_path(cr: *mut cairo_t, utf8: *c_char);
+  pub fn cairo_rel_curve_to(cr: *mut cairo_t, dx1: c_double, dy1: c_double, dx2: c_double, dy2: c_double, dx3: c_double, dy3: c_double);
+  pub fn cairo_rel_line_to(cr: *mut cairo_t, dx: c_double, dy: c_double);
+  pub fn cairo_rel_move_to(cr: *mut cairo_t, dx: c_double, dy: c_double);
+  pub fn cairo_path_extents(cr: *mut cairo_t, x1: *mut c_double, y1: *mut c_double, x2: *mut c_double, y2: *mut c_double);
+  pub fn cairo_path_destroy(path: *mut cairo_path_t);
+}
+
+// Transformations
+extern "C" {
+  pub fn cairo_translate(cr: *mut cairo_t, tx: c_double, ty: c_double);
+  pub fn cairo_scale(cr: *mut cairo_t, sx: c_double, sy: c_double);
+  pub fn cairo_rotate(cr: *mut cairo_t, angle: c_double);
+  pub fn cairo_transform(cr: *mut cairo_t, matrix: *cairo_matrix_t);
+  pub fn cairo_set_matrix(cr: *mut cairo_t, matrix: *cairo_matrix_t);
+  pub fn cairo_get_matrix(cr: *mut cairo_t, matrix: *mut cairo_matrix_t);
+  pub fn cairo_identity_matrix(cr: *mut cairo_t);
+  pub fn cairo_user_to_device(cr: *mut cairo_t, x: *mut c_double, y: *mut c_double);
+  pub fn cairo_user_to_device_distance(cr: *mut cairo_t, dx: *mut c_double, dy: *mut c_double);
+  pub fn cairo_device_to_user(cr: *mut cairo_t, x: *mut c_double, y: *mut c_double);
+  pub fn cairo_device_to_user_distance(cr: *mut cairo_t, dx: *mut c_double, dy: *mut c_double);
+}
+
+// Text
+extern "C" {
+  pub fn cairo_select_font_face(cr: *mut cairo_t, family: *c_char, slant: cairo_font_slant_t, weight: cairo_font_weight_t);
+  pub fn cairo_set_font_size(cr: *mut cairo_t, size: c_double);
+  pub fn cairo_set_font_matrix(cr: *mut cairo_t, matrix: *cairo_matrix_t);
+  pub fn cairo_get_font_matrix(cr: *mut cairo_t, matrix: *mut cairo_matrix_t);
+  pub fn cairo_set_font_options(cr: *mut cairo_t, options: *cairo_font_options_t);
+  pub fn cairo_get_font_options(cr: *mut cairo_t, options: *mut cairo_font_options_t);
+  pub fn cairo_set_font_face(cr: *mut cairo_t, font_face: *mut cairo_font_face_t);
+  pub fn cairo_get_font_face(cr: *mut cairo_t) -> *mut cairo_font_face_t;
+  pub fn cairo_set_scaled_font(cr: *mut cairo_t, scaled_font: *cairo_scaled_font_t);
+  pub fn cairo_get_scaled_font(cr: *mut cairo_t) -> *mut cairo_scaled_font_t;
+  pub fn cairo_show_text(cr: *mut cairo_t, utf8: *c_char);
+  pub fn cairo_show_glyphs(cr: *mut cairo_t, glyphs: *cairo_glyph_t, num_glyphs: c_int);
+  pub fn cairo_show_text_glyphs(cr: *mut cairo_t, utf8: *c_char, utf8_len: c_int, glyphs: *cairo_glyph_t, num_glyphs: c_int, clusters: *cairo_text_cluster_t, num_clusters: c_int, cluster_flags: cairo_text_cluster_flags_t);
+  pub fn cairo_font_extents(cr: *mut cairo_t, extents: *mut cairo_font_extents_t);
+  pub fn cairo_text_extents(cr: *mut cairo_t, utf8: *c_char, extents: *mut cairo_text_extents_t);
+  pub fn cairo_glyph_extents(cr: *mut cairo_t, glyphs: *cairo_glyph_t, num_glyphs: c_int, extents: *mut cairo_text_extents_t);
+}
+
+// Patterns
+extern "C" {
+  pub fn cairo_pattern_add_color_stop_rgb(pattern: *mut cairo_pattern_t, offset: c_double, red: c_double, green: c_double, blue: c_double);
+  pub fn cairo_pattern_add_color_stop_rgba(pattern: *mut cairo_pattern_t, offset: c_double, red: c_double, green: c_double, blue: c_double, alpha: c_double);
+  pub fn cairo_pattern_get_color_stop_count(pattern: *mut cairo_pattern_t, stop_count: *mut c_int) -> cairo_status_t;
+  pub fn cairo_pattern_get_color_stop_rgba(pattern: *mut cairo_pattern_t, index: c_int, offset: *mut c_double, red: *mut c_double, green: *mut c_double, blue: *mut c_double, alpha: *mut c_double) -> cairo_status_t;
+  pub fn cairo_pattern_create_rgb(red: c_double, green: c_double, blue: c_double) -> *mut cairo_pattern_t;
+  pub fn cairo_pattern_create_rgba(red: c_double, green: c_double, blue: c_double, alpha: c_double) -> *mut cairo_pattern_t;
+  pub fn cairo_pattern_get_rgba(pattern: *mut cairo_pattern_t, red: *mut c_double, green: *mut c_double, blue: *mut c_double, alpha: *mut c_double) -> cairo_status_t;
+  pub fn cairo_pattern_create_for_surface(surface: *mut cairo_surface_t) -> *mut cairo_pattern_t;
+  pub fn cairo_pattern_get_surface(pattern: *mut cairo_pattern_t, surface: *mut *mut cairo_surface_t) -> cairo_status_t;
+  pub fn cairo_pattern_create_linear(x0: c_double, y0: c_double, x1: c_double, y1: c_double) -> *mut cairo_pattern_t;
+  pub fn cairo_pattern_get_linear_points(pattern: *mut cairo_pattern_t, x0: *mut c_double, y0: *mut c_double, x1: *mut c_double, y1: *mut c_double) -> cairo_status_t;
+  pub fn cairo_pattern_create_radial(cx0: c_double, cy0: c_double, radius0: c_double, cx1: c_double, cy1: c_double, radius1: c_double) -> *mut cairo_pattern_t;
+  pub fn cairo_pattern_get_radial_circles(pattern: *mut cairo_pattern_t, x0: *mut c_double, y0: *mut c_double, r0: *mut c_double, x1: *mut c_double, y1: *mut c_double, r1: *mut c_double) -> cairo_status_t;
+  pub fn cairo_pattern_create_mesh() -> *mut cairo_pattern_t;
+  pub fn cairo_pattern_status(pattern: *mut cairo_pattern_t) -> cairo_status_t;
+  pub fn cairo_pattern_set_extend(pattern: *mut cairo_pattern_t, extend: cairo_extend_t);
+  pub fn cairo_pattern_get_extend(pattern: *mut cairo_pattern_t) -> cairo_extend_t;
+  pub fn cairo_pattern_set_filter(pattern: *mut cairo_pattern_t, filter: cairo_filter_t);
+  pub fn cairo_pattern_get_filter(pattern: *mut cairo_pattern_t) -> cairo_filter_t;
+  #[cfg(feature = "v1_18")]
+  pub fn cairo_pattern_set_dither(pattern: *mut cairo_pattern_t, dither: cairo_dither_t);
+  #[cfg(feature = "v1_18")]
+  pub fn cairo_pattern_get_dither(pattern: *mut cairo_pattern_t) -> cairo_dither_t;
+  pub fn cairo_pattern_set_matrix(pattern: *mut cairo_pattern_t, matrix: *cairo_matrix_t);
+  pub fn cairo_pattern_get_matrix(pattern: *mut cairo_pattern_t, matrix: *mut cairo_matrix_t);
+  pub fn cairo_pattern_get_type(pattern: *mut cairo_pattern_t) -> cairo_pattern_type_t;
+  pub fn cairo_pattern_get_reference_count(pattern: *mut cairo_pattern_t) -> c_uint;
+  pub fn cairo_pattern_set_user_data(pattern: *mut cairo_pattern_t, key: *cairo_user_data_key_t, user_data: *mut c_void, destroy: cairo_destroy_func_t) -> cairo_status_t;
+  pub fn cairo_pattern_get_user_data(pattern: *mut cairo_pattern_t, key: *cairo_user_data_key_t) -> *mut c_void;
+  pub fn cairo_pattern_reference(pattern: *mut cairo_pattern_t) -> *mut cairo_pattern_t;
+  pub fn cairo_pattern_destroy(pattern: *mut cairo_pattern_t);
+}
+
+// Mesh patterns
+extern "C" {
+  pub fn cairo_mesh_pattern_begin_patch(pattern: *mut cairo_pattern_t);
+  pub fn cairo_mesh_pattern_end_patch(pattern: *mut cairo_pattern_t);
+  pub fn cairo_mesh_pattern_move_to(pattern: *mut cairo_pattern_t, x: c_double, y: c_double);
+  pub fn cairo_mesh_pattern_line_to(pattern: *mut cairo_pattern_t, x: c_double, y: c_double);
+  pub fn cairo_mesh_pattern_curve_to(pattern: *mut cairo_pattern_t, x1: c_double, y1: c_double, x2: c_double, y2: c_double, x3: c_double, y3: c_double);
+  pub fn cairo_mesh_pattern_set_control_point(pattern: *mut cairo_pattern_t, point_num: c_uint, x: c_double, y: c_double);
+  pub fn cairo_mesh_pattern_set_corner_color_rgb(pattern: *mut cairo_pattern_t, corner_num: c_uint, red: c_double, green: c_double, blue: c_double);
+  pub fn cairo_mesh_pattern_set_corner_color_rgba(pattern: *mut cairo_pattern_t, corner_num: c_uint, red: c_double, green: c_double, blue: c_double, alpha: c_double);
+  pub fn cairo_mesh_pattern_get_patch_count(pattern: *mut cairo_pattern_t, count: *mut c_uint) -> cairo_status_t;
+  pub fn cairo_mesh_pattern_get_path(pattern: *mut cairo_pattern_t, patch_num: c_uint) -> *mut cairo_path_t;
+  pub fn cairo_mesh_pattern_get_control_point(pattern: *mut cairo_pattern_t, patch_num: c_uint, point_num: c_uint, x: *mut c_double, y: *mut c_double) -> cairo_status_t;
+  pub fn cairo_mesh_pattern_get_corner_color_rgba(pattern: *mut cairo_pattern_t, patch_num: c_uint, corner_num: c_uint, red: *mut c_double, green: *mut c_double, blue: *mut c_double, alpha: *mut c_double) -> cairo_status_t;
+}
+
+// Raster source patterns
+extern "C" {
+  pub fn cairo_pattern_create_raster_source(user_data: *mut c_void, content: cairo_content_t, width: c_int, height: c_int) -> *mut cairo_pattern_t;
+  pub fn cairo_raster_source_pattern_set_acquire(pattern: *mut cairo_pattern_t, acquire: cairo_raster_source_acquire_func_t, release: cairo_raster_source_release_func_t);
+  pub fn cairo_raster_source_pattern_set_copy(pattern: *mut cairo_pattern_t, copy: cairo_raster_source_copy_func_t);
+  pub fn cairo_raster_source_pattern_set_finish(pattern: *mut cairo_pattern_t, finish: cairo_raster_source_finish_func_t);
+}
+
+// Matrices
+extern "C" {
+  pub fn cairo_matrix_multiply(result: *mut cairo_matrix_t, a: *cairo_matrix_t, b: *cairo_matrix_t);
+  pub fn cairo_matrix_translate(matrix: *mut cairo_matrix_t, x0: c_double, y0: c_double);
+  pub fn cairo_matrix_scale(matrix: *mut cairo_matrix_t, sx: c_double, sy: c_double);
+  pub fn cairo_matrix_rotate(matrix: *mut cairo_matrix_t, radians: c_double);
+  pub fn cairo_matrix_transform_distance(matrix: *cairo_matrix_t, dx: *mut c_double, dy: *mut c_double);
+  pub fn cairo_matrix_transform_point(matrix: *cairo_matrix_t, x: *mut c_double, y: *mut c_double);
+  pub fn cairo_matrix_invert(matrix: *mut cairo_matrix_t) -> cairo_status_t;
+}
+
+// Regions
+extern "C" {
+  pub fn cairo_region_create() -> *mut cairo_region_t;
+  pub fn cairo_region_create_rectangle(rectangle: *cairo_rectangle_int_t) -> *mut cairo_region_t;
+  pub fn cairo_region_create_rectangles(rectangles: *cairo_rectangle_int_t, count: c_int) -> *mut cairo_region_t;
+  pub fn cairo_region_status(region: *mut cairo_region_t) -> cairo_status_t;
+  pub fn cairo_region_get_extents(region: *mut cairo_region_t, extents: *mut cairo_rectangle_int_t);
+  pub fn cairo_region_num_rectangles(region: *mut cairo_region_t) -> c_int;
+  pub fn cairo_region_get_rectangle(region: *mut cairo_region_t, nth: c_int, rectangle: *mut cairo_rectangle_int_t);
+  pub fn cairo_region_is_empty(region: *mut cairo_region_t) -> cairo_bool_t;
+  pub fn cairo_region_contains_point(region: *mut cairo_region_t, x: c_int, y: c_int) -> cairo_bool_t;
+  pub fn cairo_region_contains_rectangle(region: *mut cairo_region_t, rectangle: *cairo_rectangle_int_t) -> cairo_region_overlap_t;
+  pub fn cairo_region_equal(a: *cairo_region_t, b: *cairo_region_t) -> cairo_bool_t;
+  pub fn cairo_region_translate(region: *mut cairo_region_t, dx: c_int, dy: c_int);
+  pub fn cairo_region_intersect(region: *mut cairo_region_t, other: *cairo_region_t) -> cairo_status_t;
+  pub fn cairo_region_intersect_rectangle(region: *mut cairo_region_t, rectangle: *cairo_rectangle_int_t) -> cairo_status_t;
+  pub fn cairo_region_subtract(region: *mut cairo_region_t, other: *cairo_region_t) -> cairo_status_t;
+  pub fn cairo_region_subtract_rectangle(region: *mut cairo_region_t, rectangle: *cairo_rectangle_int_t) -> cairo_status_t;
+  pub fn cairo_region_union(region: *mut cairo_region_t, other: *cairo_region_t) -> cairo_status_t;
+  pub fn cairo_region_union_rectangle(region: *mut cairo_region_t, rectangle: *cairo_rectangle_int_t) -> cairo_status_t;
+  pub fn cairo_region_xor(region: *mut cairo_region_t, other: *cairo_region_t) -> cairo_status_t;
+  pub fn cairo_region_xor_rectangle(region: *mut cairo_region_t, rectangle: *cairo_rectangle_int_t) -> cairo_status_t;
+  pub fn cairo_region_reference(region: *mut cairo_region_t) -> *mut cairo_region_t;
+  pub fn cairo_region_copy(region: *cairo_region_t) -> *mut cairo_region_t;
+  pub fn cairo_region_destroy(region: *mut cairo_region_t);
+}
+
+// Devices
+extern "C" {
+  pub fn cairo_device_status(device: *mut cairo_device_t) -> cairo_status_t;
+  pub fn cairo_device_finish(device: *mut cairo_device_t);
+  pub fn cairo_device_flush(device: *mut cairo_device_t);
+  pub fn cairo_device_get_type(device: *mut cairo_device_t) -> cairo_device_type_t;
+  pub fn cairo_device_get_reference_count(device: *mut cairo_device_t) -> c_uint;
+  pub fn cairo_device_acquire(device: *mut cairo_device_t) -> cairo_status_t;
+  pub fn cairo_device_release(device: *mut cairo_device_t);
+  pub fn cairo_device_set_user_data(device: *mut cairo_device_t, key: *cairo_user_data_key_t, user_data: *mut c_void, destroy: cairo_destroy_func_t) -> cairo_status_t;
+  pub fn cairo_device_get_user_data(device: *mut cairo_device_t, key: *cairo_user_data_key_t) -> *mut c_void;
+  pub fn cairo_device_reference(device: *mut cairo_device_t) -> *mut cairo_device_t;
+  pub fn cairo_device_destroy(device: *mut cairo_device_t);
+}
+
+// Surfaces
+extern "C" {
+  pub fn cairo_surface_create_similar_image(other: *mut cairo_surface_t, format: cairo_format_t, width: c_int, height: c_int) -> *mut cairo_surface_t;
+  pub fn cairo_surface_create_for_rectangle(target: *mut cairo_surface_t, x: c_double, y: c_double, width: c_double, height: c_double) -> *mut cairo_surface_t;
+  pub fn cairo_surface_status(surface: *mut cairo_surface_t) -> cairo_status_t;
+  pub fn cairo_surface_set_user_data(surface: *mut cairo_surface_t, key: *cairo_user_data_key_t, user_data: *mut c_void, destroy: cairo_destroy_func_t) -> cairo_status_t;
+  pub fn cairo_surface_get_user_data(surface: *mut cairo_surface_t, key: *cairo_user_data_key_t) -> *mut c_void;
+  pub fn cairo_surface_set_mime_data(surface: *mut cairo_surface_t, mime_type: *c_char, data: *c_uchar, length: c_ulong, destroy: cairo_destroy_func_t, closure: *mut c_void) -> cairo_status_t;
+  pub fn cairo_surface_get_mime_data(surface: *mut cairo_surface_t, mime_type: *c_char, data: *mut *c_uchar, length: *mut c_ulong);
+  pub fn cairo_surface_supports_mime_type(surface: *mut cairo_surface_t, mime_type: *c_char) -> cairo_bool_t;
+  pub fn cairo_surface_finish(surface: *mut cairo_surface_t);
+  pub fn cairo_surface_flush(surface: *mut cairo_surface_t);
+  pub fn cairo_surface_get_device(surface: *mut cairo_surface_t) -> *mut cairo_device_t;
+  pub fn cairo_surface_get_font_options(surface: *mut cairo_surface_t, options: *mut cairo_font_options_t);
+  pub fn cairo_surface_get_content(surface: *mut cairo_surface_t) -> cairo_content_t;
+  pub fn cairo_surface_mark_dirty(surface: *mut cairo_surface_t);
+  pub fn cairo_surface_mark_dirty_rectangle(surface: *mut cairo_surface_t, x: c_int, y: c_int, width: c_int, height: c_int);
+  pub fn cairo_surface_set_device_offset(surface: *mut cairo_surface_t, x_offset: c_double, y_offset: c_double);
+  pub fn cairo_surface_get_device_offset(surface: *mut cairo_surface_t, x_offset: *mut c_double, y_offset: *mut c_double);
+  #[cfg(feature = "v1_14")]
+  pub fn cairo_surface_set_device_scale(surface: *mut cairo_surface_t, x_scale: c_double, y_scale: c_double);
+  #[cfg(feature = "v1_14")]
+  pub fn cairo_surface_get_device_scale(surface: *mut cairo_surface_t, x_scale: *mut c_double, y_scale: *mut c_double);
+  pub fn cairo_surface_set_fallback_resolution(surface: *mut cairo_surface_t, x_pixels_per_inch: c_double, y_pixels_per_inch: c_double);
+  pub fn cairo_surface_get_fallback_resolution(surface: *mut cairo_surface_t, x_pixels_per_inch: *mut c_double, y_pixels_per_inch: *mut c_double);
+  pub fn cairo_surface_get_type(surface: *mut cairo_surface_t) -> cairo_surface_type_t;
+  pub fn cairo_surface_get_reference_count(surface: *mut cairo_surface_t) -> c_uint;
+  pub fn cairo_surface_copy_page(surface: *mut cairo_surface_t);
+  pub fn cairo_surface_show_page(surface: *mut cairo_surface_t);
+  pub fn cairo_surface_reference(surface: *mut cairo_surface_t) -> *mut cairo_surface_t;
+  pub fn cairo_surface_destroy(surface: *mut cairo_surface_t);
+}
+
+// Image surfaces
+extern "C" {
+  pub fn cairo_image_surface_create(format: cairo_format_t, width: c_int, height: c_int) -> *mut cairo_surface_t;
+  pub fn cairo_image_surface_get_format(surface: *mut cairo_surface_t) -> cairo_format_t;
+  pub fn cairo_image_surface_get_width(surface: *mut cairo_surface_t) -> c_int;
+  pub fn cairo_image_surface_get_height(surface: *mut cairo_surface_t) -> c_int;
+  pub fn cairo_image_surface_get_stride(surface: *mut cairo_surface_t) -> c_int;
+  pub fn cairo_image_surface_get_data(surface: *mut cairo_surface_t) -> *mut c_uchar;
+}
+
+// Recording surfaces
+extern "C" {
+  pub fn cairo_recording_surface_create(content: cairo_content_t, extents: *cairo_rectangle_t) -> *mut cairo_surface_t;
+  pub fn cairo_recording_surface_ink_extents(surface: *mut cairo_surface_t, x0: *mut c_double, y0: *mut c_double, width: *mut c_double, height: *mut c_double);
+}
+
+// Observer surfaces
+extern "C" {
+  pub fn cairo_surface_create_observer(target: *mut cairo_surface_t, mode: cairo_surface_observer_mode_t) -> *mut cairo_surface_t;
+  pub fn cairo_surface_observer_add_paint_callback(surface: *mut cairo_surface_t, func: cairo_surface_observer_callback_t, data: *mut c_void) -> cairo_status_t;
+  pub fn cairo_surface_observer_add_mask_callback(surface: *mut cairo_surface_t, func: cairo_surface_observer_callback_t, data: *mut c_void) -> cairo_status_t;
+  pub fn cairo_surface_observer_add_fill_callback(surface: *mut cairo_surface_t, func: cairo_surface_observer_callback_t, data: *mut c_void) -> cairo_status_t;
+  pub fn cairo_surface_observer_add_stroke_callback(surface: *mut cairo_surface_t, func: cairo_surface_observer_callback_t, data: *mut c_void) -> cairo_status_t;
+  pub fn cairo_surface_observer_add_glyphs_callback(surface: *mut cairo_surface_t, func: cairo_surface_observer_callback_t, data: *mut c_void) -> cairo_status_t;
+  pub fn cairo_surface_observer_add_flush_callback(surface: *mut cairo_surface_t, func: cairo_surface_observer_callback_t, data: *mut c_void) -> cairo_status_t;
+  pub fn cairo_surface_observer_add_finish_callback(surface: *mut cairo_surface_t, func: cairo_surface_observer_callback_t, data: *mut c_void) -> cairo_status_t;
+  pub fn cairo_surface_observer_print(surface: *mut cairo_surface_t, write_func: cairo_write_func_t, closure: *mut c_void) -> cairo_status_t;
+  pub fn cairo_surface_observer_elapsed(surface: *mut cairo_surface_t) -> c_double;
+  pub fn cairo_device_observer_print(device: *mut cairo_device_t, write_func: cairo_write_func_t, closure: *mut c_void) -> cairo_status_t;
+  pub fn cairo_device_observer_elapsed(device: *mut cairo_device_t) -> c_double;
+  pub fn cairo_device_observer_paint_elapsed(device: *mut cairo_device_t) -> c_double;
+  pub fn cairo_device_observer_mask_elapsed(device: *mut cairo_device_t) -> c_double;
+  pub fn cairo_device_observer_fill_elapsed(device: *mut cairo_device_t) -> c_double;
+  pub fn cairo_device_observer_stroke_elapsed(device: *mut cairo_device_t) -> c_double;
+  pub fn cairo_device_observer_glyphs_elapsed(device: *mut cairo_device_t) -> c_double;
+}
+
+// PNG support
+#[cfg(feature = "png")]
+extern "C" {
+  pub fn cairo_image_surface_create_from_png(filename: *c_char) -> *mut cairo_surface_t;
+  pub fn cairo_surface_write_to_png(surface: *mut cairo_surface_t, filename: *c_char) -> cairo_status_t;
+}
+
+// SVG surfaces
+#[cfg(feature = "svg")]
+extern "C" {
+  pub fn cairo_svg_surface_create(filename: *c_char, width: c_double, height: c_double) -> *mut cairo_surface_t;
+  pub fn cairo_svg_surface_restrict_to_version(surface: *mut cairo_surface_t, version: cairo_svg_version_t);
+  pub fn cairo_svg_version_to_string(version: cairo_svg_version_t) -> *c_char;
+}
+
+// PDF surfaces
+#[cfg(feature = "pdf")]
+extern "C" {
+  pub fn cairo_pdf_surface_create(filename: *c_char, width_in_points: c_double, height_in_points: c_double) -> *mut cairo_surface_t;
+}
+
+// Font options
+extern "C" {
+  pub fn cairo_font_options_create() -> *mut cairo_font_options_t;
+  pub fn cairo_font_options_status(options: *mut cairo_font_options_t) -> cairo_status_t;
+  pub fn cairo_font_options_merge(options: *mut cairo_font_options_t, other: *cairo_font_options_t);
+  pub fn cairo_font_options_hash(options: *mut cairo_font_options_t) -> c_ulong;
+  pub fn cairo_font_options_equal(options: *cairo_font_options_t, other: *cairo_font_options_t) -> cairo_bool_t;
+  pub fn cairo_font_options_set_antialias(options: *mut cairo_font_options_t, antialias: cairo_antialias_t);
+  pub fn cairo_font_options_get_antialias(options: *mut cairo_font_options_t) -> cairo_antialias_t;
+  pub fn cairo_font_options_set_subpixel_order(options: *mut cairo_font_options_t, subpixel_order: cairo_subpixel_order_t);
+  pub fn cairo_font_options_get_subpixel_order(options: *mut cairo_font_options_t) -> cairo_subpixel_order_t;
+  pub fn cairo_font_options_set_hint_style(options: *mut cairo_font_options_t, hint_style: cairo_hint_style_t);
+  pub fn cairo_font_options_get_hint_style(options: *mut cairo_font_options_t) -> cairo_hint_style_t;
+  pub fn cairo_font_options_set_hint_metrics(options: *mut cairo_font_options_t, hint_metrics: cairo_hint_metrics_t);
+  pub fn cairo_font_options_get_hint_metrics(options: *mut cairo_font_options_t) -> cairo_hint_metrics_t;
+  pub fn cairo_font_options_copy(options: *cairo_font_options_t) -> *mut cairo_font_options_t;
+  pub fn cairo_font_options_destroy(options: *mut cairo_font_options_t);
+}
+
+// Font faces
+extern "C" {
+  pub fn cairo_toy_font_face_create(family: *c_char, slant: cairo_font_slant_t, weight: cairo_font_weight_t) -> *mut cairo_font_face_t;
+  pub fn cairo_toy_font_face_get_family(font_face: *mut cairo_font_face_t) -> *c_char;
+  pub fn cairo_toy_font_face_get_slant(font_face: *mut cairo_font_face_t) -> cairo_font_slant_t;
+  pub fn cairo_toy_font_face_get_weight(font_face: *mut cairo_font_face_t) -> cairo_font_weight_t;
+  pub fn cairo_font_face_status(font_face: *mut cairo_font_face_t) -> cairo_status_t;
+  pub fn cairo_font_face_get_type(font_face: *mut cairo_font_face_t) -> cairo_font_type_t;
+  pub fn cairo_font_face_get_reference_count(font_face: *mut cairo_font_face_t) -> c_uint;
+  pub fn cairo_font_face_set_user_data(font_face: *mut cairo_font_face_t, key: *cairo_user_data_key_t, user_data: *mut c_void, destroy: cairo_destroy_func_t) -> cairo_status_t;
+  pub fn cairo_font_face_get_user_data(font_face: *mut cairo_font_face_t, key: *cairo_user_data_key_t) -> *mut c_void;
+  pub fn cairo_font_face_reference(font_face: *mut cairo_font_face_t) -> *mut cairo_font_face_t;
+  pub fn cairo_font_face_destroy(font_face: *mut cairo_font_face_t);
+}
+
+// Scaled fonts
+extern "C" {
+  pub fn cairo_scaled_font_create(font_face: *mut cairo_font_face_t, font_matrix: *cairo_matrix_t, ctm: *cairo_matrix_t, options: *cairo_font_options_t) -> *mut cairo_scaled_font_t;
+  pub fn cairo_scaled_font_status(scaled_font: *mut cairo_scaled_font_t) -> cairo_status_t;
+  pub fn cairo_scaled_font_extents(scaled_font: *mut cairo_scaled_font_t, extents: *mut cairo_font_extents_t);
+  pub fn cairo_scaled_font_text_extents(scaled_font: *mut cairo_scaled_font_t, utf8: *c_char, extents: *mut cairo_text_extents_t);
+  pub fn cairo_scaled_font_glyph_extents(scaled_font: *mut cairo_scaled_font_t, glyphs: *cairo_glyph_t, num_glyphs: c_int, extents: *mut cairo_text_extents_t);
+  pub fn cairo_scaled_font_get_font_face(scaled_font: *mut cairo_scaled_font_t) -> *mut cairo_font_face_t;
+  pub fn cairo_scaled_font_get_font_options(scaled_font: *mut cairo_scaled_font_t, options: *mut cairo_font_options_t);
+  pub fn cairo_scaled_font_get_font_matrix(scaled_font: *mut cairo_scaled_font_t, font_matrix: *mut cairo_matrix_t);
+  pub fn cairo_scaled_font_get_ctm(scaled_font: *mut cairo_scaled_font_t, ctm: *mut cairo_matrix_t);
+  pub fn cairo_scaled_font_get_scale_matrix(scaled_font: *mut cairo_scaled_font_t, scale_matrix: *mut cairo_matrix_t);
+  pub fn cairo_scaled_font_get_type(scaled_font: *mut cairo_scaled_font_t) -> cairo_font_type_t;
+  pub fn cairo_scaled_font_get_reference_count(scaled_font: *mut cairo_scaled_font_t) -> c_uint;
+  pub fn cairo_scaled_font_set_user_data(scaled_font: *mut cairo_scaled_font_t, key: *cairo_user_data_key_t, user_data: *mut c_void, destroy: cairo_destroy_func_t) -> cairo_status_t;
+  pub fn cairo_scaled_font_get_user_data(scaled_font: *mut cairo_scaled_font_t, key: *cairo_user_data_key_t) -> *mut c_void;
+  pub fn cairo_scaled_font_reference(scaled_font: *mut cairo_scaled_font_t) -> *mut cairo_scaled_font_t;
+  pub fn cairo_scaled_font_destroy(scaled_font: *mut cairo_scaled_font_t);
+}
+
+// Tee surfaces
+#[cfg(feature = "tee")]
+extern "C" {
+  pub fn cairo_tee_surface_create(master: *mut cairo_surface_t) -> *mut cairo_surface_t;
+  pub fn cairo_tee_surface_add(surface: *mut cairo_surface_t, target: *mut cairo_surface_t);
+  pub fn cairo_tee_surface_remove(surface: *mut cairo_surface_t, target: *mut cairo_surface_t);
+  pub fn cairo_tee_surface_index(surface: *mut cairo_surface_t, index: c_uint) -> *mut cairo_surface_t;
+}
+
+// Script surfaces
+#[cfg(feature = "script")]
+extern "C" {
+  pub fn cairo_script_create(filename: *c_char) -> *mut cairo_device_t;
+  pub fn cairo_script_create_for_stream(write_func: cairo_write_func_t, closure: *mut c_void) -> *mut cairo_device_t;
+  pub fn cairo_script_from_recording_surface(script: *mut cairo_device_t, recording_surface: *mut cairo_surface_t) -> cairo_status_t;
+  pub fn cairo_script_get_mode(script: *mut cairo_device_t) -> cairo_script_mode_t;
+  pub fn cairo_script_set_mode(script: *mut cairo_device_t, mode: cairo_script_mode_t);
+  pub fn cairo_script_surface_create(script: *mut cairo_device_t, content: cairo_content_t, width: c_double, height: c_double) -> *mut cairo_surface_t;
+  pub fn cairo_script_surface_create_for_target(script: *mut cairo_device_t, target: *mut cairo_surface_t) -> *mut cairo_surface_t;
+  pub fn cairo_script_write_comment(script: *mut cairo_device_t, comment: *c_char, len: c_int);
+}
@@ -1,5 +1,8 @@
 //! Describe type of antialiasing when rendering text or shapes
 
+use std;
+use std::ascii::StrAsciiExt;
+
 /// Specifies the type of antialiasing to do when rendering text or shapes.
 ///
 /// As it is not necessarily clear from the above what advantages a particular antialias method provides, since 1.12, there is also a set of hints: CAIRO_ANTIALIAS_FAST: Allow the backend to degrade raster quality for speed CAIRO_ANTIALIAS_GOOD: A balance between speed and quality CAIRO_ANTIALIAS_BEST: A high-fidelity, but potentially slow, raster mode
@@ -10,6 +13,7 @@
 ///
 /// Since 1.0
 #[repr(i32)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub enum Antialias {
   /// Use the default antialiasing for the subsystem and target device, since 1.0
   Default = 0,
@@ -27,3 +31,53 @@ pub enum Antialias {
   Best = 6
 }
 
+impl std::from_str::FromStr for Antialias {
+  /// Parses antialias::Antialias, using the lower case cairo names. Case and surrounding whitespace are ignored.
+  fn from_str(s: &str) -> Option<Antialias> {
+    match s.trim().to_ascii_lower().as_slice() {
+      "default" => Some(Default),
+      "none" => Some(None),
+      "gray" | "grey" => Some(Gray),
+      "subpixel" => Some(Subpixel),
+      "fast" => Some(Fast),
+      "good" => Some(Good),
+      "best" => Some(Best),
+      _ => std::option::None
+    }
+  }
+}
+
+impl std::fmt::Show for Antialias {
+  /// Formats antialias::Antialias with the name accepted by from_str().
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let name = match *self {
+      Default => "default",
+      None => "none",
+      Gray => "gray",
+      Subpixel => "subpixel",
+      Fast => "fast",
+      Good => "good",
+      Best => "best"
+    };
+    f.pad(name)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::from_str::from_str;
+  use super::Antialias;
+
+  fn parse(s: &str) -> Option<String> {
+    from_str::<Antialias>(s).map(|antialias| format!("{}", antialias))
+  }
+
+  #[test]
+  fn names_round_trip() {
+    for name in ["default", "none", "gray", "subpixel", "fast", "good", "best"].iter() {
+      assert_eq!(parse(*name), Some(name.to_string()));
+    }
+    assert_eq!(parse("Grey"), Some("gray".to_string()));
+    assert_eq!(parse("smooth"), None);
+  }
+}
@@ -0,0 +1,422 @@
+//! Describe colors used by sources and patterns
+
+use std;
+use std::ascii::StrAsciiExt;
+
+/// A color::Color holds a translucent color as used by cairo_set_source_rgba() and cairo_pattern_add_color_stop_rgba().
+///
+/// The color and alpha components are floating point numbers in the range 0 to 1. Cairo clamps values outside that range when the color is used.
+#[deriving(Clone, PartialEq, Show)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
+pub struct Color {
+  /// red component of color
+  pub red: f64,
+  /// green component of color
+  pub green: f64,
+  /// blue component of color
+  pub blue: f64,
+  /// alpha component of color
+  pub alpha: f64
+}
+
+impl Color {
+  /// Creates an opaque color.
+  ///
+  /// red : red component of color
+  ///
+  /// green : green component of color
+  ///
+  /// blue : blue component of color
+  pub fn rgb(red: f64, green: f64, blue: f64) -> Color {
+    Color { red: red, green: green, blue: blue, alpha: 1.0 }
+  }
+
+  /// Creates a translucent color.
+  ///
+  /// red : red component of color
+  ///
+  /// green : green component of color
+  ///
+  /// blue : blue component of color
+  ///
+  /// alpha : alpha component of color
+  pub fn rgba(red: f64, green: f64, blue: f64, alpha: f64) -> Color {
+    Color { red: red, green: green, blue: blue, alpha: alpha }
+  }
+
+  /// Creates an opaque color from 8-bit channels, as used in hexadecimal color notation.
+  ///
+  /// red : red component of color, from 0 to 255
+  ///
+  /// green : green component of color, from 0 to 255
+  ///
+  /// blue : blue component of color, from 0 to 255
+  pub fn from_rgb8(red: u8, green: u8, blue: u8) -> Color {
+    Color::from_rgba8(red, green, blue, 255)
+  }
+
+  /// Creates a translucent color from 8-bit channels, as used in hexadecimal color notation.
+  ///
+  /// red : red component of color, from 0 to 255
+  ///
+  /// green : green component of color, from 0 to 255
+  ///
+  /// blue : blue component of color, from 0 to 255
+  ///
+  /// alpha : alpha component of color, from 0 to 255
+  pub fn from_rgba8(red: u8, green: u8, blue: u8, alpha: u8) -> Color {
+    Color::rgba(red as f64 / 255.0, green as f64 / 255.0, blue as f64 / 255.0, alpha as f64 / 255.0)
+  }
+
+  /// Parses a color in hexadecimal notation. The leading '#' is optional, and the forms "rgb", "rgba", "rrggbb" and "rrggbbaa" are accepted.
+  ///
+  /// hex : the color, for example "#ff8000" or "f80"
+  ///
+  /// Returns : the color, or None if hex is not a valid hexadecimal color.
+  pub fn from_hex(hex: &str) -> Option<Color> {
+    let digits = if hex.starts_with("#") { hex.slice_from(1) } else { hex };
+    if !digits.chars().all(|c| c.is_digit_radix(16)) {
+      return None;
+    }
+    let value: u32 = match std::num::from_str_radix(digits, 16) {
+      Some(value) => value,
+      None => return None
+    };
+    let nibble = |shift: uint| -> u8 { ((value >> shift) & 0xf) as u8 * 17 };
+    let byte = |shift: uint| -> u8 { ((value >> shift) & 0xff) as u8 };
+    match digits.len() {
+      3 => Some(Color::from_rgb8(nibble(8), nibble(4), nibble(0))),
+      4 => Some(Color::from_rgba8(nibble(12), nibble(8), nibble(4), nibble(0))),
+      6 => Some(Color::from_rgb8(byte(16), byte(8), byte(0))),
+      8 => Some(Color::from_rgba8(byte(24), byte(16), byte(8), byte(0))),
+      _ => None
+    }
+  }
+
+  /// Looks up one of the named colors of CSS Color Module Level 4, or "transparent". The name is matched case-insensitively.
+  ///
+  /// name : the color name, for example "cornflowerblue"
+  ///
+  /// Returns : the color, or None if name is not a known color name.
+  pub fn from_name(name: &str) -> Option<Color> {
+    let name = name.to_ascii_lower();
+    if name.as_slice() == "transparent" {
+      return Some(Color::rgba(0.0, 0.0, 0.0, 0.0));
+    }
+    for &(known, value) in NAMED_COLORS.iter() {
+      if known == name.as_slice() {
+        return Some(Color::from_rgb8((value >> 16) as u8, (value >> 8) as u8, value as u8));
+      }
+    }
+    return None;
+  }
+
+  /// Creates an opaque color from hue, saturation and lightness.
+  ///
+  /// hue : hue in degrees, wrapped into the range [0 .. 360)
+  ///
+  /// saturation : saturation, from 0 to 1
+  ///
+  /// lightness : lightness, from 0 to 1
+  pub fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Color {
+    Color::from_hsla(hue, saturation, lightness, 1.0)
+  }
+
+  /// Creates a translucent color from hue, saturation, lightness and alpha.
+  ///
+  /// hue : hue in degrees, wrapped into the range [0 .. 360)
+  ///
+  /// saturation : saturation, from 0 to 1
+  ///
+  /// lightness : lightness, from 0 to 1
+  ///
+  /// alpha : alpha component of color
+  pub fn from_hsla(hue: f64, saturation: f64, lightness: f64, alpha: f64) -> Color {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let (red, green, blue) = hue_to_rgb(hue, chroma);
+    let m = lightness - chroma / 2.0;
+    Color::rgba(red + m, green + m, blue + m, alpha)
+  }
+
+  /// Creates an opaque color from hue, saturation and value.
+  ///
+  /// hue : hue in degrees, wrapped into the range [0 .. 360)
+  ///
+  /// saturation : saturation, from 0 to 1
+  ///
+  /// value : value, from 0 to 1
+  pub fn from_hsv(hue: f64, saturation: f64, value: f64) -> Color {
+    Color::from_hsva(hue, saturation, value, 1.0)
+  }
+
+  /// Creates a translucent color from hue, saturation, value and alpha.
+  ///
+  /// hue : hue in degrees, wrapped into the range [0 .. 360)
+  ///
+  /// saturation : saturation, from 0 to 1
+  ///
+  /// value : value, from 0 to 1
+  ///
+  /// alpha : alpha component of color
+  pub fn from_hsva(hue: f64, saturation: f64, value: f64, alpha: f64) -> Color {
+    let chroma = value * saturation;
+    let (red, green, blue) = hue_to_rgb(hue, chroma);
+    let m = value - chroma;
+    Color::rgba(red + m, green + m, blue + m, alpha)
+  }
+
+  /// Creates an opaque color from linear-light sRGB components. Cairo blends in the gamma-encoded sRGB space, so the components are encoded with the sRGB transfer function.
+  ///
+  /// red : linear red component of color, from 0 to 1
+  ///
+  /// green : linear green component of color, from 0 to 1
+  ///
+  /// blue : linear blue component of color, from 0 to 1
+  pub fn from_linear_rgb(red: f64, green: f64, blue: f64) -> Color {
+    Color::from_linear_rgba(red, green, blue, 1.0)
+  }
+
+  /// Creates a translucent color from linear-light sRGB components, see color::Color::from_linear_rgb(). Alpha is not encoded.
+  ///
+  /// red : linear red component of color, from 0 to 1
+  ///
+  /// green : linear green component of color, from 0 to 1
+  ///
+  /// blue : linear blue component of color, from 0 to 1
+  ///
+  /// alpha : alpha component of color
+  pub fn from_linear_rgba(red: f64, green: f64, blue: f64, alpha: f64) -> Color {
+    Color::rgba(linear_to_srgb(red), linear_to_srgb(green), linear_to_srgb(blue), alpha)
+  }
+
+  /// Returns the color components decoded into linear-light sRGB, as (red, green, blue, alpha).
+  pub fn to_linear_rgba(&self) -> (f64, f64, f64, f64) {
+    (srgb_to_linear(self.red), srgb_to_linear(self.green), srgb_to_linear(self.blue), self.alpha)
+  }
+
+  /// Returns the color with its alpha component replaced.
+  ///
+  /// alpha : the new alpha component
+  pub fn with_alpha(&self, alpha: f64) -> Color {
+    Color::rgba(self.red, self.green, self.blue, alpha)
+  }
+}
+
+/// Computes the red, green and blue components of a fully saturated hue with the given chroma, before lightness or value is added.
+fn hue_to_rgb(hue: f64, chroma: f64) -> (f64, f64, f64) {
+  let mut hue = hue % 360.0;
+  if hue < 0.0 {
+    hue += 360.0;
+  }
+  let sector = hue / 60.0;
+  let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+  match sector as uint {
+    0 => (chroma, x, 0.0),
+    1 => (x, chroma, 0.0),
+    2 => (0.0, chroma, x),
+    3 => (0.0, x, chroma),
+    4 => (x, 0.0, chroma),
+    _ => (chroma, 0.0, x)
+  }
+}
+
+/// The sRGB transfer function.
+fn linear_to_srgb(value: f64) -> f64 {
+  if value <= 0.0031308 {
+    12.92 * value
+  } else {
+    1.055 * value.powf(1.0 / 2.4) - 0.055
+  }
+}
+
+/// The inverse of the sRGB transfer function.
+fn srgb_to_linear(value: f64) -> f64 {
+  if value <= 0.04045 {
+    value / 12.92
+  } else {
+    ((value + 0.055) / 1.055).powf(2.4)
+  }
+}
+
+/// The named colors of CSS Color Module Level 4, as 0xrrggbb values.
+static NAMED_COLORS: &'static [(&'static str, u32)] = &[
+  ("aliceblue", 0xf0f8ff),
+  ("antiquewhite", 0xfaebd7),
+  ("aqua", 0x00ffff),
+  ("aquamarine", 0x7fffd4),
+  ("azure", 0xf0ffff),
+  ("beige", 0xf5f5dc),
+  ("bisque", 0xffe4c4),
+  ("black", 0x000000),
+  ("blanchedalmond", 0xffebcd),
+  ("blue", 0x0000ff),
+  ("blueviolet", 0x8a2be2),
+  ("brown", 0xa52a2a),
+  ("burlywood", 0xdeb887),
+  ("cadetblue", 0x5f9ea0),
+  ("chartreuse", 0x7fff00),
+  ("chocolate", 0xd2691e),
+  ("coral", 0xff7f50),
+  ("cornflowerblue", 0x6495ed),
+  ("cornsilk", 0xfff8dc),
+  ("crimson", 0xdc143c),
+  ("cyan", 0x00ffff),
+  ("darkblue", 0x00008b),
+  ("darkcyan", 0x008b8b),
+  ("darkgoldenrod", 0xb8860b),
+  ("darkgray", 0xa9a9a9),
+  ("darkgreen", 0x006400),
+  ("darkgrey", 0xa9a9a9),
+  ("darkkhaki", 0xbdb76b),
+  ("darkmagenta", 0x8b008b),
+  ("darkolivegreen", 0x556b2f),
+  ("darkorange", 0xff8c00),
+  ("darkorchid", 0x9932cc),
+  ("darkred", 0x8b0000),
+  ("darksalmon", 0xe9967a),
+  ("darkseagreen", 0x8fbc8f),
+  ("darkslateblue", 0x483d8b),
+  ("darkslategray", 0x2f4f4f),
+  ("darkslategrey", 0x2f4f4f),
+  ("darkturquoise", 0x00ced1),
+  ("darkviolet", 0x9400d3),
+  ("deeppink", 0xff1493),
+  ("deepskyblue", 0x00bfff),
+  ("dimgray", 0x696969),
+  ("dimgrey", 0x696969),
+  ("dodgerblue", 0x1e90ff),
+  ("firebrick", 0xb22222),
+  ("floralwhite", 0xfffaf0),
+  ("forestgreen", 0x228b22),
+  ("fuchsia", 0xff00ff),
+  ("gainsboro", 0xdcdcdc),
+  ("ghostwhite", 0xf8f8ff),
+  ("gold", 0xffd700),
+  ("goldenrod", 0xdaa520),
+  ("gray", 0x808080),
+  ("green", 0x008000),
+  ("greenyellow", 0xadff2f),
+  ("grey", 0x808080),
+  ("honeydew", 0xf0fff0),
+  ("hotpink", 0xff69b4),
+  ("indianred", 0xcd5c5c),
+  ("indigo", 0x4b0082),
+  ("ivory", 0xfffff0),
+  ("khaki", 0xf0e68c),
+  ("lavender", 0xe6e6fa),
+  ("lavenderblush", 0xfff0f5),
+  ("lawngreen", 0x7cfc00),
+  ("lemonchiffon", 0xfffacd),
+  ("lightblue", 0xadd8e6),
+  ("lightcoral", 0xf08080),
+  ("lightcyan", 0xe0ffff),
+  ("lightgoldenrodyellow", 0xfafad2),
+  ("lightgray", 0xd3d3d3),
+  ("lightgreen", 0x90ee90),
+  ("lightgrey", 0xd3d3d3),
+  ("lightpink", 0xffb6c1),
+  ("lightsalmon", 0xffa07a),
+  ("lightseagreen", 0x20b2aa),
+  ("lightskyblue", 0x87cefa),
+  ("lightslategray", 0x778899),
+  ("lightslategrey", 0x778899),
+  ("lightsteelblue", 0xb0c4de),
+  ("lightyellow", 0xffffe0),
+  ("lime", 0x00ff00),
+  ("limegreen", 0x32cd32),
+  ("linen", 0xfaf0e6),
+  ("magenta", 0xff00ff),
+  ("maroon", 0x800000),
+  ("mediumaquamarine", 0x66cdaa),
+  ("mediumblue", 0x0000cd),
+  ("mediumorchid", 0xba55d3),
+  ("mediumpurple", 0x9370db),
+  ("mediumseagreen", 0x3cb371),
+  ("mediumslateblue", 0x7b68ee),
+  ("mediumspringgreen", 0x00fa9a),
+  ("mediumturquoise", 0x48d1cc),
+  ("mediumvioletred", 0xc71585),
+  ("midnightblue", 0x191970),
+  ("mintcream", 0xf5fffa),
+  ("mistyrose", 0xffe4e1),
+  ("moccasin", 0xffe4b5),
+  ("navajowhite", 0xffdead),
+  ("navy", 0x000080),
+  ("oldlace", 0xfdf5e6),
+  ("olive", 0x808000),
+  ("olivedrab", 0x6b8e23),
+  ("orange", 0xffa500),
+  ("orangered", 0xff4500),
+  ("orchid", 0xda70d6),
+  ("palegoldenrod", 0xeee8aa),
+  ("palegreen", 0x98fb98),
+  ("paleturquoise", 0xafeeee),
+  ("palevioletred", 0xdb7093),
+  ("papayawhip", 0xffefd5),
+  ("peachpuff", 0xffdab9),
+  ("peru", 0xcd853f),
+  ("pink", 0xffc0cb),
+  ("plum", 0xdda0dd),
+  ("powderblue", 0xb0e0e6),
+  ("purple", 0x800080),
+  ("rebeccapurple", 0x663399),
+  ("red", 0xff0000),
+  ("rosybrown", 0xbc8f8f),
+  ("royalblue", 0x4169e1),
+  ("saddlebrown", 0x8b4513),
+  ("salmon", 0xfa8072),
+  ("sandybrown", 0xf4a460),
+  ("seagreen", 0x2e8b57),
+  ("seashell", 0xfff5ee),
+  ("sienna", 0xa0522d),
+  ("silver", 0xc0c0c0),
+  ("skyblue", 0x87ceeb),
+  ("slateblue", 0x6a5acd),
+  ("slategray", 0x708090),
+  ("slategrey", 0x708090),
+  ("snow", 0xfffafa),
+  ("springgreen", 0x00ff7f),
+  ("steelblue", 0x4682b4),
+  ("tan", 0xd2b48c),
+  ("teal", 0x008080),
+  ("thistle", 0xd8bfd8),
+  ("tomato", 0xff6347),
+  ("turquoise", 0x40e0d0),
+  ("violet", 0xee82ee),
+  ("wheat", 0xf5deb3),
+  ("white", 0xffffff),
+  ("whitesmoke", 0xf5f5f5),
+  ("yellow", 0xffff00),
+  ("yellowgreen", 0x9acd32)
+];
+
+#[cfg(test)]
+mod test {
+  use super::{Color, NAMED_COLORS};
+
+  #[test]
+  fn names() {
+    assert_eq!(Color::from_name("cornflowerblue"), Some(Color::from_rgb8(0x64, 0x95, 0xed)));
+    assert_eq!(Color::from_name("RebeccaPurple"), Some(Color::from_rgb8(0x66, 0x33, 0x99)));
+    assert_eq!(Color::from_name("transparent"), Some(Color::rgba(0.0, 0.0, 0.0, 0.0)));
+    assert_eq!(Color::from_name("bluish"), None);
+  }
+
+  #[test]
+  fn names_are_lower_case_and_unique() {
+    for (i, &(name, _)) in NAMED_COLORS.iter().enumerate() {
+      assert!(name.chars().all(|c| c.is_lowercase()));
+      assert!(NAMED_COLORS.slice_from(i + 1).iter().all(|&(other, _)| other != name));
+    }
+  }
+
+  #[test]
+  fn hex() {
+    assert_eq!(Color::from_hex("#f80"), Some(Color::from_rgb8(0xff, 0x88, 0x00)));
+    assert_eq!(Color::from_hex("f808"), Some(Color::from_rgba8(0xff, 0x88, 0x00, 0x88)));
+    assert_eq!(Color::from_hex("#ff8000"), Some(Color::from_rgb8(0xff, 0x80, 0x00)));
+    assert_eq!(Color::from_hex("#ff800080"), Some(Color::from_rgba8(0xff, 0x80, 0x00, 0x80)));
+    assert_eq!(Color::from_hex("#12345"), None);
+    assert_eq!(Color::from_hex("#ggg"), None);
+  }
+}
@@ -0,0 +1,8 @@
+//! Report failures of the Rust API
+
+/// error::Error describes why an operation of the Rust API failed.
+#[deriving(Clone, PartialEq, Show)]
+pub enum Error {
+  /// cairo reported an error status
+  CairoError(super::Status)
+}
@@ -0,0 +1,27 @@
+//! Query which parts of the API the cairo library at run-time provides
+
+/// feature::Feature names a part of the cairo API that was added after cairo 1.12, the oldest release this crate supports. The functions of these features are only compiled with the matching cargo feature, such as v1_14, which links against a cairo library of at least that version.
+///
+/// A program built without the cargo feature can still use cairo::supports() to find out whether the library loaded at run-time provides the feature, for example to report it.
+#[deriving(Clone, PartialEq, Show)]
+pub enum Feature {
+  /// surface::Surface::set_device_scale(), since 1.14
+  DeviceScale,
+  /// tags for links, destinations and document structure, see tag::Tag, since 1.16
+  Tags,
+  /// hairline stroking with Cairo::set_hairline(), since 1.18
+  Hairlines,
+  /// pattern::Pattern::set_dither(), since 1.18
+  Dithering
+}
+
+impl Feature {
+  /// Returns the version of cairo that introduced the feature, encoded as by cairo::version_encode().
+  pub fn since(&self) -> i32 {
+    match *self {
+      DeviceScale => super::version_encode(1, 14, 0),
+      Tags => super::version_encode(1, 16, 0),
+      Hairlines | Dithering => super::version_encode(1, 18, 0)
+    }
+  }
+}
@@ -1,5 +1,8 @@
 //! Describe which rule used to select how paths are filled.
 
+use std;
+use std::ascii::StrAsciiExt;
+
 /// fill_rule::FillRule is used to select how paths are filled. For both fill rules, whether or not a point is included in the fill is determined by taking a ray from that point to infinity and looking at intersections with the path. The ray can be in any direction, as long as it doesn't pass through the end point of a segment or have a tricky intersection such as intersecting tangent to the path. (Note that filling is not actually implemented in this way. This is just a description of the rule that is applied.)
 /// 
 /// The default fill rule is CAIRO_FILL_RULE_WINDING.
@@ -8,6 +11,7 @@
 /// 
 /// Since 1.0
 #[repr(i32)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub enum FillRule {
   /// If the path crosses the ray from left-to-right, counts +1. If the path crosses the ray from right to left, counts -1. (Left and right are determined from the perspective of looking along the ray from the starting point.) If the total count is non-zero, the point will be filled. (Since 1.0)
   Winding = 0,
@@ -15,3 +19,24 @@ pub enum FillRule {
   EvenOdd = 1
 }
 
+impl std::from_str::FromStr for FillRule {
+  /// Parses fill_rule::FillRule, using the names of the SVG fill-rule property, "nonzero" and "evenodd". The cairo name "winding" is accepted as well. Case and surrounding whitespace are ignored.
+  fn from_str(s: &str) -> Option<FillRule> {
+    match s.trim().to_ascii_lower().as_slice() {
+      "nonzero" | "winding" | "non-zero" => Some(Winding),
+      "evenodd" | "even-odd" => Some(EvenOdd),
+      _ => None
+    }
+  }
+}
+
+impl std::fmt::Show for FillRule {
+  /// Formats fill_rule::FillRule with the name accepted by from_str().
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let name = match *self {
+      Winding => "nonzero",
+      EvenOdd => "evenodd"
+    };
+    f.pad(name)
+  }
+}
@@ -3,6 +3,7 @@
 use std;
 use super::DeepClone;
 use libc;
+use ffi;
 
 /// An opaque structure holding all options that are used when rendering fonts.
 /// 
@@ -45,6 +46,7 @@ pub struct ScaledFont {
 /// Note that the offsets given by x and y are not cumulative. When drawing or measuring text, each glyph is individually positioned with respect to the overall origin
 /// 
 /// Since 1.0
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub struct Glyph {
   /// glyph index in the font. The exact interpretation of the glyph index depends on the font technology being used.
   index: i64,
@@ -73,6 +75,7 @@ pub struct Cluster {
 /// Because font metrics are in user-space coordinates, they are mostly, but not entirely, independent of the current transformation matrix. If you call cairo_scale(cr, 2.0, 2.0), text will be drawn twice as big, but the reported text extents will not be doubled. They will change slightly due to hinting (so you can't assume that metrics are independent of the transformation matrix), but otherwise will remain unchanged.
 /// 
 /// Since 1.0
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub struct FontExtents {
   /// the distance that the font extends above the baseline. Note that this is not always exactly equal to the maximum of the extents of all the glyphs in the font, but rather is picked to express the font designer's intent as to how the font should align with elements above it.
   ascent: f64,
@@ -89,6 +92,7 @@ pub struct FontExtents {
 /// The font::TextExtends structure stores the extents of a single glyph or a string of glyphs in user-space coordinates. Because text extents are in user-space coordinates, they are mostly, but not entirely, independent of the current transformation matrix. If you call cairo_scale(cr, 2.0, 2.0), text will be drawn twice as big, but the reported text extents will not be doubled. They will change slightly due to hinting (so you can't assume that metrics are independent of the transformation matrix), but otherwise will remain unchanged.
 /// 
 /// Since 1.0
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub struct TextExtents {
   /// the horizontal distance from the origin to the leftmost part of the glyphs as drawn. Positive if the glyphs lie entirely to the right of the origin.
   x_bearing: f64,
@@ -112,7 +116,7 @@ impl Options {
   /// Since 1.0
   pub fn new() -> Options {
     unsafe {
-      let foreign_result = cairo_font_options_create();
+      let foreign_result = Options { opaque: ffi::cairo_font_options_create() };
       return foreign_result;
     }
   }
@@ -126,7 +130,7 @@ impl Options {
   /// Since 1.0
   pub fn status(&mut self) -> super::Status {
     unsafe {
-      let foreign_result = cairo_font_options_status(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_font_options_status(self.opaque));
       return foreign_result;
     }
   }
@@ -140,7 +144,7 @@ impl Options {
   /// Since 1.0
   pub fn merge(&mut self, other: &Options) {
     unsafe {
-      cairo_font_options_merge(self.opaque, other);
+      ffi::cairo_font_options_merge(self.opaque, other.opaque);
     }
   }
 
@@ -153,7 +157,7 @@ impl Options {
   /// Since 1.0
   pub fn hash(&mut self) -> i64 {
     unsafe {
-      let foreign_result = cairo_font_options_hash(self.opaque);
+      let foreign_result = ffi::cairo_font_options_hash(self.opaque) as i64;
       return foreign_result;
     }
   }
@@ -169,7 +173,7 @@ impl Options {
   /// Since 1.0
   pub fn equal(&mut self, other: &Options) -> bool {
     unsafe {
-      let foreign_result = cairo_font_options_equal(self.opaque, other.opaque as *libc::c_void);
+      let foreign_result = ffi::cairo_font_options_equal(self.opaque, other.opaque);
       return foreign_result != 0;
     }
   }
@@ -183,7 +187,7 @@ impl Options {
   /// Since 1.0
   pub fn set_antialias(&mut self, antialias: super::antialias::Antialias) {
     unsafe {
-      cairo_font_options_set_antialias(self.opaque, antialias);
+      ffi::cairo_font_options_set_antialias(self.opaque, antialias as ffi::cairo_antialias_t);
     }
   }
 
@@ -196,7 +200,7 @@ impl Options {
   /// Since 1.0
   pub fn get_antialias(&mut self) -> super::antialias::Antialias {
     unsafe {
-      let foreign_result = cairo_font_options_get_antialias(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_font_options_get_antialias(self.opaque));
       return foreign_result;
     }
   }
@@ -210,7 +214,7 @@ impl Options {
   /// Since 1.0
   pub fn set_subpixel_order(&mut self, subpixel_order: subpixel_order::SubpixelOrder) {
     unsafe {
-      cairo_font_options_set_subpixel_order(self.opaque, subpixel_order);
+      ffi::cairo_font_options_set_subpixel_order(self.opaque, subpixel_order as ffi::cairo_subpixel_order_t);
     }
   }
 
@@ -223,7 +227,7 @@ impl Options {
   /// Since 1.0
   pub fn get_subpixel_order(&mut self) -> subpixel_order::SubpixelOrder {
     unsafe {
-      let foreign_result = cairo_font_options_get_subpixel_order(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_font_options_get_subpixel_order(self.opaque));
       return foreign_result;
     }
   }
@@ -237,7 +241,7 @@ impl Options {
   /// Since 1.0
   pub fn set_hint_style(&mut self, hint_style: hint_style::HintStyle) {
     unsafe {
-      cairo_font_options_set_hint_style(self.opaque, hint_style);
+      ffi::cairo_font_options_set_hint_style(self.opaque, hint_style as ffi::cairo_hint_style_t);
     }
   }
 
@@ -250,7 +254,7 @@ impl Options {
   /// Since 1.0
   pub fn get_hint_style(&mut self) -> hint_style::HintStyle {
     unsafe {
-      let foreign_result = cairo_font_options_get_hint_style(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_font_options_get_hint_style(self.opaque));
       return foreign_result;
     }
   }
@@ -264,7 +268,7 @@ impl Options {
   /// Since 1.0
   pub fn set_hint_metrics(&mut self, hint_metrics: hint_metrics::HintMetrics) {
     unsafe {
-      cairo_font_options_set_hint_metrics(self.opaque, hint_metrics);
+      ffi::cairo_font_options_set_hint_metrics(self.opaque, hint_metrics as ffi::cairo_hint_metrics_t);
     }
   }
 
@@ -277,45 +281,27 @@ impl Options {
   /// Since 1.0
   pub fn get_hint_metrics(&mut self) -> hint_metrics::HintMetrics {
     unsafe {
-      let foreign_result = cairo_font_options_get_hint_metrics(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_font_options_get_hint_metrics(self.opaque));
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_font_options_create() -> Options;
-  fn cairo_font_options_status(self_value: *mut libc::c_void) -> super::Status;
-  fn cairo_font_options_merge(self_value: *mut libc::c_void, other: *Options);
-  fn cairo_font_options_hash(self_value: *mut libc::c_void) -> i64;
-  fn cairo_font_options_equal(self_value: *mut libc::c_void, other: *libc::c_void) -> i32;
-  fn cairo_font_options_set_antialias(self_value: *mut libc::c_void, antialias: super::antialias::Antialias);
-  fn cairo_font_options_get_antialias(self_value: *mut libc::c_void) -> super::antialias::Antialias;
-  fn cairo_font_options_set_subpixel_order(self_value: *mut libc::c_void, subpixel_order: subpixel_order::SubpixelOrder);
-  fn cairo_font_options_get_subpixel_order(self_value: *mut libc::c_void) -> subpixel_order::SubpixelOrder;
-  fn cairo_font_options_set_hint_style(self_value: *mut libc::c_void, hint_style: hint_style::HintStyle);
-  fn cairo_font_options_get_hint_style(self_value: *mut libc::c_void) -> hint_style::HintStyle;
-  fn cairo_font_options_set_hint_metrics(self_value: *mut libc::c_void, hint_metrics: hint_metrics::HintMetrics);
- fn cairo_font_options_get_hint_metrics(self_value: *mut libc::c_void) -> hint_metrics::HintMetrics;
-}
 
 impl std::clone::Clone for Options {
   fn clone(&self) -> Options {
     unsafe {
-      let foreign_result = cairo_font_options_copy(self.opaque as *libc::c_void);
+      let foreign_result = Options { opaque: ffi::cairo_font_options_copy(self.opaque) };
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_font_options_copy(self_value: *libc::c_void) -> Options;
-}
 
 impl DeepClone for Options {
   fn deep_clone(&self) -> Options {
     unsafe {
-      let foreign_result = cairo_font_options_copy(self.opaque as *libc::c_void);
+      let foreign_result = Options { opaque: ffi::cairo_font_options_copy(self.opaque) };
       return foreign_result;
     }
   }
@@ -324,14 +310,11 @@ impl DeepClone for Options {
 impl std::ops::Drop for Options {
   fn drop(&mut self) {
     unsafe {
-      cairo_font_options_destroy(self.opaque);
+      ffi::cairo_font_options_destroy(self.opaque);
     }
   }
 }
 
-extern {
-  fn cairo_font_options_destroy(self_value: *mut libc::c_void);
-}
 
 impl FontFace {
   /// Creates a font face from a triplet of family, slant, and weight. These font faces are used in implementation of the the Cairo "toy" font API.
@@ -352,7 +335,7 @@ impl FontFace {
   pub fn toy(family: &str, slant: slant::Slant, weight: weight::Weight) -> FontFace {
     unsafe {
       use std::c_str::ToCStr;
-      let foreign_result = cairo_toy_font_face_create(family.to_c_str().unwrap(), slant, weight);
+      let foreign_result = FontFace { opaque: ffi::cairo_toy_font_face_create(family.to_c_str().unwrap(), slant as ffi::cairo_font_slant_t, weight as ffi::cairo_font_weight_t) };
       return foreign_result;
     }
   }
@@ -366,7 +349,7 @@ impl FontFace {
   /// Since 1.8
   pub fn toy_get_family(&mut self) -> std::c_str::CString {
     unsafe {
-      let foreign_result = cairo_toy_font_face_get_family(self.opaque);
+      let foreign_result = ffi::cairo_toy_font_face_get_family(self.opaque);
       return std::c_str::CString::new(foreign_result, false);
     }
   }
@@ -380,7 +363,7 @@ impl FontFace {
   /// Since 1.8
   pub fn toy_get_slant(&mut self) -> slant::Slant {
     unsafe {
-      let foreign_result = cairo_toy_font_face_get_slant(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_toy_font_face_get_slant(self.opaque));
       return foreign_result;
     }
   }
@@ -394,7 +377,7 @@ impl FontFace {
   /// Since 1.8
   pub fn toy_get_weight(&mut self) -> slant::Slant {
     unsafe {
-      let foreign_result = cairo_toy_font_face_get_weight(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_toy_font_face_get_weight(self.opaque));
       return foreign_result;
     }
   }
@@ -408,7 +391,7 @@ impl FontFace {
   /// Since 1.0
   pub fn status(&mut self) -> super::Status {
     unsafe {
-      let foreign_result = cairo_font_face_status(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_font_face_status(self.opaque));
       return foreign_result;
     }
   }
@@ -422,7 +405,7 @@ impl FontFace {
   /// Since 1.2
   pub fn get_type(&mut self) -> font_type::FontType {
     unsafe {
-      let foreign_result = cairo_font_face_get_type(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_font_face_get_type(self.opaque));
       return foreign_result;
     }
   }
@@ -436,46 +419,81 @@ impl FontFace {
   /// Since 1.4
   pub fn reference_count(&mut self) -> i32 {
     unsafe {
-      let foreign_result = cairo_font_face_get_reference_count(self.opaque);
+      let foreign_result = ffi::cairo_font_face_get_reference_count(self.opaque) as i32;
+      return foreign_result;
+    }
+  }
+
+  /// Attach user data to font_face. To remove user data from font_face, call font::FontFace::remove_user_data() with the same key. The data is dropped when it is replaced or removed, or when the last reference to font_face is destroyed.
+  ///
+  /// font_face : a font::FontFace
+  ///
+  /// key : the address of a user_data::Key to attach the user data to
+  ///
+  /// data : the user data to attach to font_face
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or CAIRO_STATUS_NO_MEMORY if a slot could not be allocated for the user data.
+  ///
+  /// Since 1.0
+  pub fn set_user_data<T: 'static>(&mut self, key: &'static super::user_data::Key, data: T) -> super::Status {
+    unsafe {
+      let foreign_result = super::attach_user_data(self.opaque, ffi::cairo_font_face_set_user_data, key, Some(data));
       return foreign_result;
     }
   }
-}
 
-extern {
-  fn cairo_toy_font_face_create(family: *libc::c_char, slant: slant::Slant, weight: weight::Weight) -> FontFace;
-  fn cairo_toy_font_face_get_family(self_value: *mut libc::c_void) -> *i8;
-  fn cairo_toy_font_face_get_slant(self_value: *mut libc::c_void) -> slant::Slant;
-  fn cairo_toy_font_face_get_weight(self_value: *mut libc::c_void) -> slant::Slant;
-  fn cairo_font_face_status(self_value: *mut libc::c_void) -> super::Status;
-  fn cairo_font_face_get_type(self_value: *mut libc::c_void) -> font_type::FontType;
-  fn cairo_font_face_get_reference_count(self_value: *mut libc::c_void) -> i32;
+  /// Remove the user data attached to font_face with key, dropping it.
+  ///
+  /// font_face : a font::FontFace
+  ///
+  /// key : the address of the user_data::Key the user data was attached to
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or the error status of font_face.
+  ///
+  /// Since 1.0
+  pub fn remove_user_data(&mut self, key: &'static super::user_data::Key) -> super::Status {
+    unsafe {
+      let foreign_result = super::attach_user_data::<()>(self.opaque, ffi::cairo_font_face_set_user_data, key, None);
+      return foreign_result;
+    }
+  }
+
+  /// Return user data previously attached to font_face using the specified key. If no user data has been attached with the given key, or it is not a T, this function returns None. The user data is cloned, since other references to the object may replace or remove it.
+  ///
+  /// font_face : a font::FontFace
+  ///
+  /// key : the address of the user_data::Key the user data was attached to
+  ///
+  /// Returns : a clone of the user data previously attached or None.
+  ///
+  /// Since 1.0
+  pub fn get_user_data<T: Clone + 'static>(&mut self, key: &'static super::user_data::Key) -> Option<T> {
+    unsafe {
+      let foreign_result = super::find_user_data(self.opaque, ffi::cairo_font_face_get_user_data, key);
+      return foreign_result;
+    }
+  }
 }
 
+
 impl std::clone::Clone for FontFace {
   fn clone(&self) -> FontFace {
     unsafe {
-      let foreign_result = cairo_font_face_reference(self.opaque as *libc::c_void);
+      let foreign_result = FontFace { opaque: ffi::cairo_font_face_reference(self.opaque) };
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_font_face_reference(self_value: *libc::c_void) -> FontFace;
-}
 
 impl std::ops::Drop for FontFace {
   fn drop(&mut self) {
     unsafe {
-      cairo_font_face_destroy(self.opaque);
+      ffi::cairo_font_face_destroy(self.opaque);
     }
   }
 }
 
-extern {
-  fn cairo_font_face_destroy(self_value: *mut libc::c_void);
-}
 
 impl ScaledFont {
   /// Creates a font::ScaledFont object from a font face and matrices that describe the size of the font and the environment in which it will be used.
@@ -493,7 +511,7 @@ impl ScaledFont {
   /// Since 1.0
   pub fn new(font_face: &mut FontFace, font_matrix: &super::matrix::Matrix, ctm: &super::matrix::Matrix, options: &mut Options) -> ScaledFont {
     unsafe {
-      let foreign_result = cairo_scaled_font_create(font_face, font_matrix, ctm, options);
+      let foreign_result = ScaledFont { opaque: ffi::cairo_scaled_font_create(font_face.opaque, font_matrix as *super::matrix::Matrix as *ffi::cairo_matrix_t, ctm as *super::matrix::Matrix as *ffi::cairo_matrix_t, options.opaque) };
       return foreign_result;
     }
   }
@@ -507,7 +525,7 @@ impl ScaledFont {
   /// Since 1.0
   pub fn status(&mut self) -> super::Status {
     unsafe {
-      let foreign_result = cairo_scaled_font_status(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_scaled_font_status(self.opaque));
       return foreign_result;
     }
   }
@@ -522,7 +540,7 @@ impl ScaledFont {
   pub fn font_extents(&mut self) -> FontExtents {
     unsafe {
       let mut extents:FontExtents = std::intrinsics::init();
-      cairo_scaled_font_extents(self.opaque, &mut extents);
+      ffi::cairo_scaled_font_extents(self.opaque, &mut extents as *mut FontExtents as *mut ffi::cairo_font_extents_t);
       return extents;
     }
   }
@@ -541,7 +559,7 @@ impl ScaledFont {
   pub fn text_extents(&mut self, utf8: &str) -> TextExtents {
     unsafe {
       let mut extents:TextExtents = std::intrinsics::init();
-      cairo_scaled_font_text_extents(self.opaque, utf8.to_c_str().unwrap(), &mut extents);
+      ffi::cairo_scaled_font_text_extents(self.opaque, utf8.to_c_str().unwrap(), &mut extents as *mut TextExtents as *mut ffi::cairo_text_extents_t);
       return extents;
     }
   }
@@ -562,7 +580,7 @@ impl ScaledFont {
   pub fn glyph_extents(&mut self, glyphs: &[Glyph]) -> TextExtents {
     unsafe {
       let mut extents:TextExtents = std::intrinsics::init();
-      cairo_scaled_font_glyph_extents(self.opaque, glyphs.as_ptr(), glyphs.len() as i32, &mut extents);
+      ffi::cairo_scaled_font_glyph_extents(self.opaque, glyphs.as_ptr() as *Glyph as *ffi::cairo_glyph_t, glyphs.len() as i32, &mut extents as *mut TextExtents as *mut ffi::cairo_text_extents_t);
       return extents;
     }
   }
@@ -576,7 +594,7 @@ impl ScaledFont {
   /// Since 1.2
   pub fn get_font_face(&mut self) -> FontFace {
     unsafe {
-      let foreign_result = cairo_scaled_font_get_font_face(self.opaque);
+      let foreign_result = FontFace { opaque: ffi::cairo_scaled_font_get_font_face(self.opaque) };
       return foreign_result;
     }
   }
@@ -588,9 +606,9 @@ impl ScaledFont {
   /// options : return value for the font options
   /// 
   /// Since 1.2
-  pub fn get_font_options(&mut self, options: FontExtents) {
+  pub fn get_font_options(&mut self, options: &mut Options) {
     unsafe {
-      cairo_scaled_font_get_font_options(self.opaque, options);
+      ffi::cairo_scaled_font_get_font_options(self.opaque, options.opaque);
     }
   }
 
@@ -606,7 +624,7 @@ impl ScaledFont {
   pub fn get_font_matrix(&mut self) -> super::matrix::Matrix {
     unsafe {
       let mut font_matrix:super::matrix::Matrix = std::intrinsics::init();
-      cairo_scaled_font_get_font_matrix(self.opaque, &mut font_matrix);
+      ffi::cairo_scaled_font_get_font_matrix(self.opaque, &mut font_matrix as *mut super::matrix::Matrix as *mut ffi::cairo_matrix_t);
       return font_matrix;
     }
   }
@@ -623,7 +641,7 @@ impl ScaledFont {
   pub fn get_ctm(&mut self) -> super::matrix::Matrix {
     unsafe {
       let mut ctm:super::matrix::Matrix = std::intrinsics::init();
-      cairo_scaled_font_get_ctm(self.opaque, &mut ctm);
+      ffi::cairo_scaled_font_get_ctm(self.opaque, &mut ctm as *mut super::matrix::Matrix as *mut ffi::cairo_matrix_t);
       return ctm;
     }
   }
@@ -640,7 +658,7 @@ impl ScaledFont {
   pub fn get_scale_matrix(&mut self) -> super::matrix::Matrix {
     unsafe {
       let mut scale_matrix:super::matrix::Matrix = std::intrinsics::init();
-      cairo_scaled_font_get_scale_matrix(self.opaque, &mut scale_matrix);
+      ffi::cairo_scaled_font_get_scale_matrix(self.opaque, &mut scale_matrix as *mut super::matrix::Matrix as *mut ffi::cairo_matrix_t);
       return scale_matrix;
     }
   }
@@ -656,7 +674,7 @@ impl ScaledFont {
   /// Since 1.2
   pub fn get_type(&mut self) -> font_type::FontType {
     unsafe {
-      let foreign_result = cairo_scaled_font_get_type(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_scaled_font_get_type(self.opaque));
       return foreign_result;
     }
   }
@@ -672,51 +690,81 @@ impl ScaledFont {
   /// Since 1.4
   pub fn reference_count(&mut self) -> i32 {
     unsafe {
-      let foreign_result = cairo_scaled_font_get_reference_count(self.opaque);
+      let foreign_result = ffi::cairo_scaled_font_get_reference_count(self.opaque) as i32;
+      return foreign_result;
+    }
+  }
+
+  /// Attach user data to scaled_font. To remove user data from scaled_font, call font::ScaledFont::remove_user_data() with the same key. The data is dropped when it is replaced or removed, or when the last reference to scaled_font is destroyed.
+  ///
+  /// scaled_font : a font::ScaledFont
+  ///
+  /// key : the address of a user_data::Key to attach the user data to
+  ///
+  /// data : the user data to attach to scaled_font
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or CAIRO_STATUS_NO_MEMORY if a slot could not be allocated for the user data.
+  ///
+  /// Since 1.4
+  pub fn set_user_data<T: 'static>(&mut self, key: &'static super::user_data::Key, data: T) -> super::Status {
+    unsafe {
+      let foreign_result = super::attach_user_data(self.opaque, ffi::cairo_scaled_font_set_user_data, key, Some(data));
+      return foreign_result;
+    }
+  }
+
+  /// Remove the user data attached to scaled_font with key, dropping it.
+  ///
+  /// scaled_font : a font::ScaledFont
+  ///
+  /// key : the address of the user_data::Key the user data was attached to
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or the error status of scaled_font.
+  ///
+  /// Since 1.4
+  pub fn remove_user_data(&mut self, key: &'static super::user_data::Key) -> super::Status {
+    unsafe {
+      let foreign_result = super::attach_user_data::<()>(self.opaque, ffi::cairo_scaled_font_set_user_data, key, None);
       return foreign_result;
     }
   }
-}
 
-extern {
-  fn cairo_scaled_font_create(font_face: *mut FontFace, font_matrix: *super::matrix::Matrix, ctm: *super::matrix::Matrix, options: *mut Options) -> ScaledFont;
-  fn cairo_scaled_font_status(self_value: *mut libc::c_void) -> super::Status;
-  fn cairo_scaled_font_extents(self_value: *mut libc::c_void, extents: *mut FontExtents);
-  fn cairo_scaled_font_text_extents(self_value: *mut libc::c_void, utf8: *libc::c_char, extents: *mut TextExtents);
-  fn cairo_scaled_font_glyph_extents(self_value: *mut libc::c_void, glyphs: *Glyph, glyphs_length: i32, extents: *mut TextExtents);
-  fn cairo_scaled_font_get_font_face(self_value: *mut libc::c_void) -> FontFace;
-  fn cairo_scaled_font_get_font_options(self_value: *mut libc::c_void, options: FontExtents);
-  fn cairo_scaled_font_get_font_matrix(self_value: *mut libc::c_void, font_matrix: *mut super::matrix::Matrix);
-  fn cairo_scaled_font_get_ctm(self_value: *mut libc::c_void, ctm: *mut super::matrix::Matrix);
-  fn cairo_scaled_font_get_scale_matrix(self_value: *mut libc::c_void, scale_matrix: *mut super::matrix::Matrix);
-  fn cairo_scaled_font_get_type(self_value: *mut libc::c_void) -> font_type::FontType;
-  fn cairo_scaled_font_get_reference_count(self_value: *mut libc::c_void) -> i32;
+  /// Return user data previously attached to scaled_font using the specified key. If no user data has been attached with the given key, or it is not a T, this function returns None. The user data is cloned, since other references to the object may replace or remove it.
+  ///
+  /// scaled_font : a font::ScaledFont
+  ///
+  /// key : the address of the user_data::Key the user data was attached to
+  ///
+  /// Returns : a clone of the user data previously attached or None.
+  ///
+  /// Since 1.4
+  pub fn get_user_data<T: Clone + 'static>(&mut self, key: &'static super::user_data::Key) -> Option<T> {
+    unsafe {
+      let foreign_result = super::find_user_data(self.opaque, ffi::cairo_scaled_font_get_user_data, key);
+      return foreign_result;
+    }
+  }
 }
 
+
 impl std::clone::Clone for ScaledFont {
   fn clone(&self) -> ScaledFont {
     unsafe {
-      let foreign_result = cairo_scaled_font_reference(self.opaque as *libc::c_void);
+      let foreign_result = ScaledFont { opaque: ffi::cairo_scaled_font_reference(self.opaque) };
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_scaled_font_reference(self_value: *libc::c_void) -> ScaledFont;
-}
 
 impl std::ops::Drop for ScaledFont {
   fn drop(&mut self) {
     unsafe {
-      cairo_scaled_font_destroy(self.opaque);
+      ffi::cairo_scaled_font_destroy(self.opaque);
     }
   }
 }
 
-extern {
-  fn cairo_scaled_font_destroy(self_value: *mut libc::c_void);
-}
 
 pub mod cluster_flags;
 pub mod font_type;
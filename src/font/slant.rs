@@ -1,5 +1,8 @@
 //! Describe font slant
 
+use std;
+use std::ascii::StrAsciiExt;
+
 /// Specifies variants of a font face based on their slant.
 /// 
 /// Since 1.0
@@ -13,3 +16,26 @@ pub enum Slant {
   Oblique = 2
 }
 
+impl std::from_str::FromStr for Slant {
+  /// Parses font::slant::Slant, using the names of the CSS font-style property. Case and surrounding whitespace are ignored.
+  fn from_str(s: &str) -> Option<Slant> {
+    match s.trim().to_ascii_lower().as_slice() {
+      "normal" => Some(Normal),
+      "italic" => Some(Italic),
+      "oblique" => Some(Oblique),
+      _ => None
+    }
+  }
+}
+
+impl std::fmt::Show for Slant {
+  /// Formats font::slant::Slant with the name accepted by from_str().
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let name = match *self {
+      Normal => "normal",
+      Italic => "italic",
+      Oblique => "oblique"
+    };
+    f.pad(name)
+  }
+}
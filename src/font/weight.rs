@@ -1,5 +1,8 @@
 //! Describe font weight
 
+use std;
+use std::ascii::StrAsciiExt;
+
 /// Specifies variants of a font face based on their weight.
 /// 
 /// Since 1.0i
@@ -11,3 +14,24 @@ pub enum Weight {
   Bold = 1
 }
 
+impl std::from_str::FromStr for Weight {
+  /// Parses font::weight::Weight, using the names of the CSS font-weight property, "normal" and "bold". The numeric weights "400" and "700" are accepted as well. Case and surrounding whitespace are ignored.
+  fn from_str(s: &str) -> Option<Weight> {
+    match s.trim().to_ascii_lower().as_slice() {
+      "normal" | "400" => Some(Normal),
+      "bold" | "700" => Some(Bold),
+      _ => None
+    }
+  }
+}
+
+impl std::fmt::Show for Weight {
+  /// Formats font::weight::Weight with the name accepted by from_str().
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let name = match *self {
+      Normal => "normal",
+      Bold => "bold"
+    };
+    f.pad(name)
+  }
+}
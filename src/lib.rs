@@ -1,12 +1,14 @@
-#![crate_id = "cairo"]
+#![crate_name = "cairo"]
+#![crate_type = "lib"]
+#![feature(unsafe_destructor, unboxed_closures)]
 #![deny(missing_doc)]
 
 //! A Cairo bindings library.
 
 extern crate libc;
-
-#[link(name = "cairo")]
-extern {}
+extern crate "cairo_sys" as ffi;
+#[cfg(feature = "serde")]
+extern crate serialize;
 
 /// Was removed from std, therefore declared here.
 pub trait DeepClone {
@@ -20,6 +22,7 @@ pub trait DeepClone {
 /// 
 /// Since 1.0
 #[repr(i32)]
+#[deriving(Clone, PartialEq, Show)]
 pub enum Status {
   /// no error has occurred (Since 1.0)
   Success = 0,
@@ -102,6 +105,22 @@ pub enum Status {
   // LastStatus = 39,
 }
 
+/// A data structure for holding a rectangle.
+///
+/// Since 1.4
+#[deriving(Clone, PartialEq, Show)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
+pub struct Rectangle {
+  /// X coordinate of the left side of the rectangle
+  pub x: f64,
+  /// Y coordinate of the the top side of the rectangle
+  pub y: f64,
+  /// width of the rectangle
+  pub width: f64,
+  /// height of the rectangle
+  pub height: f64
+}
+
 /// A Cairo contains the current state of the rendering device, including coordinates of yet to be drawn shapes.
 /// 
 /// Cairo contexts, as Cairo objects are named, are central to cairo and all drawing with cairo is always done to a Cairo object.
@@ -126,7 +145,7 @@ impl Cairo {
   /// Since 1.0
   pub fn new(surface: &mut surface::Surface) -> Cairo {
     unsafe {
-      let foreign_result = cairo_create(surface.opaque);
+      let foreign_result = Cairo { opaque: ffi::cairo_create(surface.opaque) };
       return foreign_result;
     }
   }
@@ -140,7 +159,7 @@ impl Cairo {
   /// Since 1.0
   pub fn status(&mut self) -> Status {
     unsafe {
-      let foreign_result = cairo_status(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_status(self.opaque));
       return foreign_result;
     }
   }
@@ -154,7 +173,7 @@ impl Cairo {
   /// Since 1.0
   pub fn save(&mut self) {
     unsafe {
-      cairo_save(self.opaque);
+      ffi::cairo_save(self.opaque);
     }
   }
 
@@ -165,7 +184,7 @@ impl Cairo {
   /// Since 1.0
   pub fn restore(&mut self) {
     unsafe {
-      cairo_restore(self.opaque);
+      ffi::cairo_restore(self.opaque);
     }
   }
 
@@ -180,7 +199,7 @@ impl Cairo {
   /// Since 1.0
   pub fn get_target(&mut self) -> surface::Surface {
     unsafe {
-      let foreign_result = cairo_get_target(self.opaque);
+      let foreign_result = surface::Surface { opaque: ffi::cairo_get_target(self.opaque) };
       return foreign_result.clone();
     }
   }
@@ -212,7 +231,7 @@ impl Cairo {
   /// Since 1.2
   pub fn push_group(&mut self) {
     unsafe {
-      cairo_push_group(self.opaque);
+      ffi::cairo_push_group(self.opaque);
     }
   }
 
@@ -227,7 +246,7 @@ impl Cairo {
   /// Since 1.2
   pub fn push_group_with_content(&mut self, content: surface::content::Content) {
     unsafe {
-      cairo_push_group_with_content(self.opaque, content);
+      ffi::cairo_push_group_with_content(self.opaque, content as ffi::cairo_content_t);
     }
   }
 
@@ -242,7 +261,7 @@ impl Cairo {
   /// Since 1.2
   pub fn pop_group(&mut self) -> pattern::Pattern {
     unsafe {
-      let foreign_result = cairo_pop_group(self.opaque);
+      let foreign_result = pattern::Pattern { opaque: ffi::cairo_pop_group(self.opaque) };
       return foreign_result;
     }
   }
@@ -266,7 +285,7 @@ impl Cairo {
   /// Since 1.2
   pub fn pop_group_to_source(&mut self) {
     unsafe {
-      cairo_pop_group_to_source(self.opaque);
+      ffi::cairo_pop_group_to_source(self.opaque);
     }
   }
 
@@ -281,7 +300,7 @@ impl Cairo {
   /// Since 1.2
   pub fn get_group_target(&mut self) -> surface::Surface {
     unsafe {
-      let foreign_result = cairo_get_group_target(self.opaque);
+      let foreign_result = surface::Surface { opaque: ffi::cairo_get_group_target(self.opaque) };
       return foreign_result.clone();
     }
   }
@@ -303,7 +322,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_source_rgb(&mut self, red: f64, green: f64, blue: f64) {
     unsafe {
-      cairo_set_source_rgb(self.opaque, red, green, blue);
+      ffi::cairo_set_source_rgb(self.opaque, red, green, blue);
     }
   }
 
@@ -326,10 +345,19 @@ impl Cairo {
   /// Since 1.0
   pub fn set_source_rgba(&mut self, red: f64, green: f64, blue: f64, alpha: f64) {
     unsafe {
-      cairo_set_source_rgba(self.opaque, red, green, blue, alpha);
+      ffi::cairo_set_source_rgba(self.opaque, red, green, blue, alpha);
     }
   }
 
+  /// Sets the source pattern within cr to a color, see cairo_set_source_rgba().
+  ///
+  /// cr : a cairo context
+  ///
+  /// color : the color to use as source
+  pub fn set_source_color(&mut self, color: color::Color) {
+    self.set_source_rgba(color.red, color.green, color.blue, color.alpha);
+  }
+
   /// Sets the source pattern within cr to source. This pattern will then be used for any subsequent drawing operation until a new source pattern is set.
   ///
   /// Note: The pattern's transformation matrix will be locked to the user space in effect at the time of cairo_set_source(). This means that further modifications of the current transformation matrix will not affect the source pattern. See cairo_pattern_set_matrix().
@@ -343,7 +371,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_source(&mut self, source: &mut pattern::Pattern) {
     unsafe {
-      cairo_set_source(self.opaque, source.opaque);
+      ffi::cairo_set_source(self.opaque, source.opaque);
     }
   }
 
@@ -364,7 +392,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_source_surface(&mut self, surface: &mut surface::Surface, x: f64, y: f64) {
     unsafe {
-      cairo_set_source_surface(self.opaque, surface.opaque, x, y);
+      ffi::cairo_set_source_surface(self.opaque, surface.opaque, x, y);
     }
   }
 
@@ -377,7 +405,7 @@ impl Cairo {
   /// Since 1.0
   pub fn get_source(&mut self) -> pattern::Pattern {
     unsafe {
-      let foreign_result = cairo_get_source(self.opaque);
+      let foreign_result = pattern::Pattern { opaque: ffi::cairo_get_source(self.opaque) };
       return foreign_result;
     }
   }
@@ -393,7 +421,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_antialias(&mut self, antialias: antialias::Antialias) {
     unsafe {
-      cairo_set_antialias(self.opaque, antialias);
+      ffi::cairo_set_antialias(self.opaque, antialias as ffi::cairo_antialias_t);
     }
   }
 
@@ -406,7 +434,7 @@ impl Cairo {
   /// Since 1.0
   pub fn get_antialias(&mut self) -> antialias::Antialias {
     unsafe {
-      let foreign_result = cairo_get_antialias(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_get_antialias(self.opaque));
       return foreign_result;
     }
   }
@@ -434,7 +462,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_dash(&mut self, dashes: &[f64], offset: f64) {
     unsafe {
-      cairo_set_dash(self.opaque, dashes.as_ptr(), dashes.len() as i32, offset);
+      ffi::cairo_set_dash(self.opaque, dashes.as_ptr(), dashes.len() as i32, offset);
     }
   }
 
@@ -449,7 +477,7 @@ impl Cairo {
   /// Since 1.4
   pub fn get_dash_count(&mut self) -> i32 {
     unsafe {
-      let foreign_result = cairo_get_dash_count(self.opaque);
+      let foreign_result = ffi::cairo_get_dash_count(self.opaque);
       return foreign_result;
     }
   }
@@ -469,7 +497,7 @@ impl Cairo {
       let dashes_len = self.get_dash_count() as uint;
       let mut dashes:Vec<f64> = Vec::from_elem(dashes_len, Zero::zero());
       let mut offset:f64 = std::intrinsics::init();
-      cairo_get_dash(self.opaque, dashes.as_mut_ptr(), &mut offset);
+      ffi::cairo_get_dash(self.opaque, dashes.as_mut_ptr(), &mut offset);
       return (dashes, offset);
     }
   }
@@ -485,7 +513,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_fill_rule(&mut self, fill_rule: fill_rule::FillRule) {
     unsafe {
-      cairo_set_fill_rule(self.opaque, fill_rule);
+      ffi::cairo_set_fill_rule(self.opaque, fill_rule as ffi::cairo_fill_rule_t);
     }
   }
 
@@ -498,7 +526,7 @@ impl Cairo {
   /// Since 1.0
   pub fn get_fill_rule(&mut self) -> fill_rule::FillRule {
     unsafe {
-      let foreign_result = cairo_get_fill_rule(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_get_fill_rule(self.opaque));
       return foreign_result;
     }
   }
@@ -517,7 +545,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_line_cap(&mut self, line_cap: line_cap::LineCap) {
     unsafe {
-      cairo_set_line_cap(self.opaque, line_cap);
+      ffi::cairo_set_line_cap(self.opaque, line_cap as ffi::cairo_line_cap_t);
     }
   }
 
@@ -530,7 +558,7 @@ impl Cairo {
   /// Since 1.0
   pub fn get_line_cap(&mut self) -> line_cap::LineCap {
     unsafe {
-      let foreign_result = cairo_get_line_cap(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_get_line_cap(self.opaque));
       return foreign_result;
     }
   }
@@ -548,7 +576,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_line_join(&mut self, line_join: line_join::LineJoin) {
     unsafe {
-      cairo_set_line_join(self.opaque, line_join);
+      ffi::cairo_set_line_join(self.opaque, line_join as ffi::cairo_line_join_t);
     }
   }
 
@@ -561,7 +589,7 @@ impl Cairo {
   /// Since 1.0
   pub fn get_line_join(&mut self) -> line_join::LineJoin {
     unsafe {
-      let foreign_result = cairo_get_line_join(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_get_line_join(self.opaque));
       return foreign_result;
     }
   }
@@ -581,7 +609,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_line_width(&mut self, width: f64) {
     unsafe {
-      cairo_set_line_width(self.opaque, width);
+      ffi::cairo_set_line_width(self.opaque, width);
     }
   }
 
@@ -594,11 +622,42 @@ impl Cairo {
   /// Since 1.0
   pub fn get_line_width(&mut self) -> f64 {
     unsafe {
-      let foreign_result = cairo_get_line_width(self.opaque);
+      let foreign_result = ffi::cairo_get_line_width(self.opaque);
       return foreign_result;
     }
   }
 
+  /// Sets lines within the cairo context to be hairlines. Hairlines are logically zero-width lines that are drawn at the thinnest renderable width possible in the current context.
+  ///
+  /// On surfaces with native hairline support, the native hairline functionality will be used. Surfaces that support hairlines include: pdf/ps/svg/xcb. The line width set by cairo_set_line_width() is ignored while hairline mode is enabled.
+  ///
+  /// cr : a cairo context
+  ///
+  /// set_hairline : whether or not to set hairline mode
+  ///
+  /// Since 1.18
+  #[cfg(feature = "v1_18")]
+  pub fn set_hairline(&mut self, set_hairline: bool) {
+    unsafe {
+      ffi::cairo_set_hairline(self.opaque, set_hairline as ffi::cairo_bool_t);
+    }
+  }
+
+  /// Returns whether or not hairline mode is set, as set by cairo_set_hairline().
+  ///
+  /// cr : a cairo context
+  ///
+  /// Returns : whether hairline mode is set
+  ///
+  /// Since 1.18
+  #[cfg(feature = "v1_18")]
+  pub fn get_hairline(&mut self) -> bool {
+    unsafe {
+      let foreign_result = ffi::cairo_get_hairline(self.opaque);
+      return foreign_result != 0;
+    }
+  }
+
   /// Sets the current miter limit within the cairo context.
   ///
   /// If the current line join style is set to CAIRO_LINE_JOIN_MITER (see cairo_set_line_join()), the miter limit is used to determine whether the lines should be joined with a bevel instead of a miter. Cairo divides the length of the miter by the line width. If the result is greater than the miter limit, the style is converted to a bevel.
@@ -616,7 +675,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_miter_limit(&mut self, limit: f64) {
     unsafe {
-      cairo_set_miter_limit(self.opaque, limit);
+      ffi::cairo_set_miter_limit(self.opaque, limit);
     }
   }
 
@@ -629,7 +688,7 @@ impl Cairo {
   /// Since 1.0
   pub fn get_miter_limit(&mut self) -> f64 {
     unsafe {
-      let foreign_result = cairo_get_miter_limit(self.opaque);
+      let foreign_result = ffi::cairo_get_miter_limit(self.opaque);
       return foreign_result;
     }
   }
@@ -645,7 +704,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_operator(&mut self, operator: operator::Operator) {
     unsafe {
-      cairo_set_operator(self.opaque, operator);
+      ffi::cairo_set_operator(self.opaque, operator as ffi::cairo_operator_t);
     }
   }
 
@@ -658,7 +717,7 @@ impl Cairo {
   /// Since 1.0
   pub fn get_operator(&mut self) -> operator::Operator {
     unsafe {
-      let foreign_result = cairo_get_operator(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_get_operator(self.opaque));
       return foreign_result;
     }
   }
@@ -672,7 +731,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_tolerance(&mut self, tolerance: f64) {
     unsafe {
-      cairo_set_tolerance(self.opaque, tolerance);
+      ffi::cairo_set_tolerance(self.opaque, tolerance);
     }
   }
 
@@ -685,7 +744,7 @@ impl Cairo {
   /// Since 1.0
   pub fn get_tolerance(&mut self) -> f64 {
     unsafe {
-      let foreign_result = cairo_get_tolerance(self.opaque);
+      let foreign_result = ffi::cairo_get_tolerance(self.opaque);
       return foreign_result;
     }
   }
@@ -703,7 +762,7 @@ impl Cairo {
   /// Since 1.0
   pub fn clip(&mut self) {
     unsafe {
-      cairo_clip(self.opaque);
+      ffi::cairo_clip(self.opaque);
     }
   }
 
@@ -720,7 +779,7 @@ impl Cairo {
   /// Since 1.0
   pub fn clip_preserve(&mut self) {
     unsafe {
-      cairo_clip_preserve(self.opaque);
+      ffi::cairo_clip_preserve(self.opaque);
     }
   }
 
@@ -743,11 +802,62 @@ impl Cairo {
       let mut y1:f64 = std::intrinsics::init();
       let mut x2:f64 = std::intrinsics::init();
       let mut y2:f64 = std::intrinsics::init();
-      cairo_clip_extents(self.opaque, &mut x1, &mut y1, &mut x2, &mut y2);
+      ffi::cairo_clip_extents(self.opaque, &mut x1, &mut y1, &mut x2, &mut y2);
       return (x1, y1, x2, y2);
     }
   }
 
+  /// Gets the current clip region as a list of rectangles in user coordinates.
+  ///
+  /// cr : a cairo context
+  ///
+  /// Returns : the rectangles of the current clip, or CAIRO_STATUS_CLIP_NOT_REPRESENTABLE if the clip region cannot be represented as a list of user-space rectangles. Other errors of cr are returned as they are.
+  ///
+  /// Since 1.4
+  pub fn clip_rectangles(&mut self) -> Result<Vec<Rectangle>, error::Error> {
+    unsafe {
+      let list = ffi::cairo_copy_clip_rectangle_list(self.opaque);
+      let status: Status = std::mem::transmute((*list).status);
+      let result = match status {
+        Success => {
+          let data = (*list).rectangles as *Rectangle;
+          let mut rectangles = Vec::with_capacity((*list).num_rectangles as uint);
+          for i in range(0, (*list).num_rectangles) {
+            rectangles.push((*data.offset(i as int)).clone());
+          }
+          Ok(rectangles)
+        },
+        status => Err(error::CairoError(status))
+      };
+      ffi::cairo_rectangle_list_destroy(list);
+      return result;
+    }
+  }
+
+  /// Gets the current clip as a region::Region. The rectangles of the clip are in user coordinates and are rounded outward to whole units, so the region covers at least the current clip.
+  ///
+  /// cr : a cairo context
+  ///
+  /// Returns : the region covering the current clip, or the error of cairo_copy_clip_rectangle_list().
+  pub fn clip_region(&mut self) -> Result<region::Region, error::Error> {
+    let rectangles = match self.clip_rectangles() {
+      Ok(rectangles) => rectangles,
+      Err(error) => return Err(error)
+    };
+    let pixels: Vec<region::Rectangle> = rectangles.iter().map(|rectangle| {
+      let x = rectangle.x.floor() as i32;
+      let y = rectangle.y.floor() as i32;
+      let right = (rectangle.x + rectangle.width).ceil() as i32;
+      let bottom = (rectangle.y + rectangle.height).ceil() as i32;
+      region::Rectangle::new(x, y, right - x, bottom - y)
+    }).collect();
+    let mut region = region::Region::rectangles(pixels.as_slice());
+    return match region.status() {
+      Success => Ok(region),
+      status => Err(error::CairoError(status))
+    };
+  }
+
   /// Tests whether the given point is inside the area that would be visible through the current clip, i.e. the area that would be filled by a cairo_paint() operation.
   ///
   /// See cairo_clip(), and cairo_clip_preserve().
@@ -763,7 +873,7 @@ impl Cairo {
   /// Since 1.10
   pub fn in_clip(&mut self, x: f64, y: f64) -> bool {
     unsafe {
-      let foreign_result = cairo_in_clip(self.opaque, x, y);
+      let foreign_result = ffi::cairo_in_clip(self.opaque, x, y);
       return foreign_result != 0;
     }
   }
@@ -777,7 +887,7 @@ impl Cairo {
   /// Since 1.0
   pub fn reset_clip(&mut self) {
     unsafe {
-      cairo_reset_clip(self.opaque);
+      ffi::cairo_reset_clip(self.opaque);
     }
   }
 
@@ -788,7 +898,7 @@ impl Cairo {
   /// Since 1.0
   pub fn fill(&mut self) {
     unsafe {
-      cairo_fill(self.opaque);
+      ffi::cairo_fill(self.opaque);
     }
   }
 
@@ -801,7 +911,7 @@ impl Cairo {
   /// Since 1.0i
   pub fn fill_preserve(&mut self) {
     unsafe {
-      cairo_fill_preserve(self.opaque);
+      ffi::cairo_fill_preserve(self.opaque);
     }
   }
 
@@ -830,7 +940,7 @@ impl Cairo {
       let mut y1:f64 = std::intrinsics::init();
       let mut x2:f64 = std::intrinsics::init();
       let mut y2:f64 = std::intrinsics::init();
-      cairo_fill_extents(self.opaque, &mut x1, &mut y1, &mut x2, &mut y2);
+      ffi::cairo_fill_extents(self.opaque, &mut x1, &mut y1, &mut x2, &mut y2);
       return (x1, y1, x2, y2);
     }
   }
@@ -850,7 +960,7 @@ impl Cairo {
   /// Since 1.0
   pub fn in_fill(&mut self, x: f64, y: f64) -> bool {
     unsafe {
-      let foreign_result = cairo_in_fill(self.opaque, x, y);
+      let foreign_result = ffi::cairo_in_fill(self.opaque, x, y);
       return foreign_result != 0;
     }
   }
@@ -864,7 +974,7 @@ impl Cairo {
   /// Since 1.0
   pub fn mask(&mut self, pattern: &mut pattern::Pattern) {
     unsafe {
-      cairo_mask(self.opaque, pattern.opaque);
+      ffi::cairo_mask(self.opaque, pattern.opaque);
     }
   }
 
@@ -881,7 +991,7 @@ impl Cairo {
   /// Since 1.0
   pub fn mask_surface(&mut self, surface: &mut surface::Surface, surface_x: f64, surface_y: f64) {
     unsafe {
-      cairo_mask_surface(self.opaque, surface.opaque, surface_x, surface_y);
+      ffi::cairo_mask_surface(self.opaque, surface.opaque, surface_x, surface_y);
     }
   }
 
@@ -892,7 +1002,7 @@ impl Cairo {
   /// Since 1.0
   pub fn paint(&mut self) {
     unsafe {
-      cairo_paint(self.opaque);
+      ffi::cairo_paint(self.opaque);
     }
   }
 
@@ -905,7 +1015,7 @@ impl Cairo {
   /// Since 1.0
   pub fn paint_with_alpha(&mut self, alpha: f64) {
     unsafe {
-      cairo_paint_with_alpha(self.opaque, alpha);
+      ffi::cairo_paint_with_alpha(self.opaque, alpha);
     }
   }
 
@@ -924,7 +1034,7 @@ impl Cairo {
   /// Since 1.0
   pub fn stroke(&mut self) {
     unsafe {
-      cairo_stroke(self.opaque);
+      ffi::cairo_stroke(self.opaque);
     }
   }
 
@@ -937,7 +1047,7 @@ impl Cairo {
   /// Since 1.0
   pub fn stroke_preserve(&mut self) {
     unsafe {
-      cairo_stroke_preserve(self.opaque);
+      ffi::cairo_stroke_preserve(self.opaque);
     }
   }
 
@@ -966,7 +1076,7 @@ impl Cairo {
       let mut y1:f64 = std::intrinsics::init();
       let mut x2:f64 = std::intrinsics::init();
       let mut y2:f64 = std::intrinsics::init();
-      cairo_stroke_extents(self.opaque, &mut x1, &mut y1, &mut x2, &mut y2);
+      ffi::cairo_stroke_extents(self.opaque, &mut x1, &mut y1, &mut x2, &mut y2);
       return (x1, y1, x2, y2);
     }
   }
@@ -986,7 +1096,7 @@ impl Cairo {
   /// Since 1.0
   pub fn in_stroke(&mut self, x: f64, y: f64) -> bool {
     unsafe {
-      let foreign_result = cairo_in_stroke(self.opaque, x, y);
+      let foreign_result = ffi::cairo_in_stroke(self.opaque, x, y);
       return foreign_result != 0;
     }
   }
@@ -1000,7 +1110,7 @@ impl Cairo {
   /// Since 1.0
   pub fn copy_page(&mut self) {
     unsafe {
-      cairo_copy_page(self.opaque);
+      ffi::cairo_copy_page(self.opaque);
     }
   }
 
@@ -1013,7 +1123,7 @@ impl Cairo {
   /// Since 1.0
   pub fn show_page(&mut self) {
     unsafe {
-      cairo_show_page(self.opaque);
+      ffi::cairo_show_page(self.opaque);
     }
   }
 
@@ -1026,7 +1136,57 @@ impl Cairo {
   /// Since 1.4
   pub fn get_reference_count(&mut self) -> i32 {
     unsafe {
-      let foreign_result = cairo_get_reference_count(self.opaque);
+      let foreign_result = ffi::cairo_get_reference_count(self.opaque) as i32;
+      return foreign_result;
+    }
+  }
+
+  /// Attach user data to cr. To remove user data from cr, call Cairo::remove_user_data() with the same key. The data is dropped when it is replaced or removed, or when the last reference to cr is destroyed.
+  ///
+  /// cr : a Cairo
+  ///
+  /// key : the address of a user_data::Key to attach the user data to
+  ///
+  /// data : the user data to attach to cr
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or CAIRO_STATUS_NO_MEMORY if a slot could not be allocated for the user data.
+  ///
+  /// Since 1.4
+  pub fn set_user_data<T: 'static>(&mut self, key: &'static user_data::Key, data: T) -> Status {
+    unsafe {
+      let foreign_result = attach_user_data(self.opaque, ffi::cairo_set_user_data, key, Some(data));
+      return foreign_result;
+    }
+  }
+
+  /// Remove the user data attached to cr with key, dropping it.
+  ///
+  /// cr : a Cairo
+  ///
+  /// key : the address of the user_data::Key the user data was attached to
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or the error status of cr.
+  ///
+  /// Since 1.4
+  pub fn remove_user_data(&mut self, key: &'static user_data::Key) -> Status {
+    unsafe {
+      let foreign_result = attach_user_data::<()>(self.opaque, ffi::cairo_set_user_data, key, None);
+      return foreign_result;
+    }
+  }
+
+  /// Return user data previously attached to cr using the specified key. If no user data has been attached with the given key, or it is not a T, this function returns None. The user data is cloned, since other references to the object may replace or remove it.
+  ///
+  /// cr : a Cairo
+  ///
+  /// key : the address of the user_data::Key the user data was attached to
+  ///
+  /// Returns : a clone of the user data previously attached or None.
+  ///
+  /// Since 1.4
+  pub fn get_user_data<T: Clone + 'static>(&mut self, key: &'static user_data::Key) -> Option<T> {
+    unsafe {
+      let foreign_result = find_user_data(self.opaque, ffi::cairo_get_user_data, key);
       return foreign_result;
     }
   }
@@ -1045,7 +1205,7 @@ impl Cairo {
   /// Since 1.0
   pub fn copy_path(&mut self) -> path::Path {
     unsafe {
-      let foreign_result = cairo_copy_path(self.opaque);
+      let foreign_result = path::Path { opaque: ffi::cairo_copy_path(self.opaque) as *mut libc::c_void };
       return foreign_result;
     }
   }
@@ -1067,7 +1227,7 @@ impl Cairo {
   /// Since 1.0
   pub fn copy_path_flat(&mut self) -> path::Path {
     unsafe {
-      let foreign_result = cairo_copy_path_flat(self.opaque);
+      let foreign_result = path::Path { opaque: ffi::cairo_copy_path_flat(self.opaque) as *mut libc::c_void };
       return foreign_result;
     }
   }
@@ -1081,7 +1241,7 @@ impl Cairo {
   /// Since 1.0
   pub fn append_path(&mut self, path: &path::Path) {
     unsafe {
-      cairo_append_path(self.opaque, path.opaque as *libc::c_void);
+      ffi::cairo_append_path(self.opaque, path.opaque as *ffi::cairo_path_t);
     }
   }
 
@@ -1094,7 +1254,7 @@ impl Cairo {
   /// Since 1.6
   pub fn has_current_point(&mut self) -> bool {
     unsafe {
-      let foreign_result = cairo_has_current_point(self.opaque);
+      let foreign_result = ffi::cairo_has_current_point(self.opaque);
       return foreign_result != 0;
     }
   }
@@ -1120,7 +1280,7 @@ impl Cairo {
     unsafe {
       let mut x:f64 = std::intrinsics::init();
       let mut y:f64 = std::intrinsics::init();
-      cairo_get_current_point(self.opaque, &mut x, &mut y);
+      ffi::cairo_get_current_point(self.opaque, &mut x, &mut y);
       return (x, y);
     }
   }
@@ -1132,7 +1292,7 @@ impl Cairo {
   /// Since 1.0
   pub fn new_path(&mut self) {
     unsafe {
-      cairo_new_path(self.opaque);
+      ffi::cairo_new_path(self.opaque);
     }
   }
 
@@ -1147,7 +1307,7 @@ impl Cairo {
   /// Since 1.2
   pub fn new_sub_path(&mut self) {
     unsafe {
-      cairo_new_sub_path(self.opaque);
+      ffi::cairo_new_sub_path(self.opaque);
     }
   }
 
@@ -1164,7 +1324,7 @@ impl Cairo {
   /// Since 1.0
   pub fn close_path(&mut self) {
     unsafe {
-      cairo_close_path(self.opaque);
+      ffi::cairo_close_path(self.opaque);
     }
   }
 
@@ -1203,7 +1363,7 @@ impl Cairo {
   /// Since 1.0
   pub fn arc(&mut self, xc: f64, yc: f64, radius: f64, angle1: f64, angle2: f64) {
     unsafe {
-      cairo_arc(self.opaque, xc, yc, radius, angle1, angle2);
+      ffi::cairo_arc(self.opaque, xc, yc, radius, angle1, angle2);
     }
   }
 
@@ -1226,7 +1386,7 @@ impl Cairo {
   /// Since 1.0
   pub fn arc_negative(&mut self, xc: f64, yc: f64, radius: f64, angle1: f64, angle2: f64) {
     unsafe {
-      cairo_arc_negative(self.opaque, xc, yc, radius, angle1, angle2);
+      ffi::cairo_arc_negative(self.opaque, xc, yc, radius, angle1, angle2);
     }
   }
 
@@ -1252,7 +1412,7 @@ impl Cairo {
   /// Since 1.0
   pub fn curve_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, x3: f64, y3: f64) {
     unsafe {
-      cairo_curve_to(self.opaque, x1, y1, x2, y2, x3, y3);
+      ffi::cairo_curve_to(self.opaque, x1, y1, x2, y2, x3, y3);
     }
   }
 
@@ -1269,7 +1429,7 @@ impl Cairo {
   /// Since 1.0
   pub fn line_to(&mut self, x: f64, y: f64) {
     unsafe {
-      cairo_line_to(self.opaque, x, y);
+      ffi::cairo_line_to(self.opaque, x, y);
     }
   }
 
@@ -1284,7 +1444,7 @@ impl Cairo {
   /// Since 1.0
   pub fn move_to(&mut self, x: f64, y: f64) {
     unsafe {
-      cairo_move_to(self.opaque, x, y);
+      ffi::cairo_move_to(self.opaque, x, y);
     }
   }
 
@@ -1313,7 +1473,7 @@ impl Cairo {
   /// Since 1.0
   pub fn rectangle(&mut self, x: f64, y: f64, width: f64, height: f64) {
     unsafe {
-      cairo_rectangle(self.opaque, x, y, width, height);
+      ffi::cairo_rectangle(self.opaque, x, y, width, height);
     }
   }
 
@@ -1328,7 +1488,7 @@ impl Cairo {
   /// Since 1.0
   pub fn glyph_path(&mut self, glyphs: &[font::Glyph]) {
     unsafe {
-      cairo_glyph_path(self.opaque, glyphs.as_ptr(), glyphs.len() as i32);
+      ffi::cairo_glyph_path(self.opaque, glyphs.as_ptr() as *font::Glyph as *ffi::cairo_glyph_t, glyphs.len() as i32);
     }
   }
 
@@ -1348,7 +1508,7 @@ impl Cairo {
   pub fn text_path(&mut self, text_path: &str) {
     unsafe {
       use std::c_str::ToCStr;
-      cairo_text_path(self.opaque, text_path.to_c_str().unwrap());
+      ffi::cairo_text_path(self.opaque, text_path.to_c_str().unwrap());
     }
   }
 
@@ -1375,7 +1535,7 @@ impl Cairo {
   /// Since 1.0
   pub fn rel_curve_to(&mut self, dx1: f64, dy1: f64, dx2: f64, dy2: f64, dx3: f64, dy3: f64) {
     unsafe {
-      cairo_rel_curve_to(self.opaque, dx1, dy1, dx2, dy2, dx3, dy3);
+      ffi::cairo_rel_curve_to(self.opaque, dx1, dy1, dx2, dy2, dx3, dy3);
     }
   }
 
@@ -1394,7 +1554,7 @@ impl Cairo {
   /// Since 1.0
   pub fn rel_line_to(&mut self, dx: f64, dy: f64) {
     unsafe {
-      cairo_rel_line_to(self.opaque, dx, dy);
+      ffi::cairo_rel_line_to(self.opaque, dx, dy);
     }
   }
 
@@ -1413,7 +1573,7 @@ impl Cairo {
   /// Since 1.0
   pub fn rel_move_to(&mut self, dx: f64, dy: f64) {
     unsafe {
-      cairo_rel_move_to(self.opaque, dx, dy);
+      ffi::cairo_rel_move_to(self.opaque, dx, dy);
     }
   }
 
@@ -1442,7 +1602,7 @@ impl Cairo {
       let mut y1:f64 = std::intrinsics::init();
       let mut x2:f64 = std::intrinsics::init();
       let mut y2:f64 = std::intrinsics::init();
-      cairo_path_extents(self.opaque, &mut x1, &mut y1, &mut x2, &mut y2);
+      ffi::cairo_path_extents(self.opaque, &mut x1, &mut y1, &mut x2, &mut y2);
       return (x1, y1, x2, y2);
     }
   }
@@ -1458,7 +1618,7 @@ impl Cairo {
   /// Since 1.0
   pub fn translate(&mut self, tx: f64, ty: f64) {
     unsafe {
-      cairo_translate(self.opaque, tx, ty);
+      ffi::cairo_translate(self.opaque, tx, ty);
     }
   }
 
@@ -1473,7 +1633,7 @@ impl Cairo {
   /// Since 1.0
   pub fn scale(&mut self, sx: f64, sy: f64) {
     unsafe {
-      cairo_scale(self.opaque, sx, sy);
+      ffi::cairo_scale(self.opaque, sx, sy);
     }
   }
 
@@ -1486,7 +1646,7 @@ impl Cairo {
   /// Since 1.0
   pub fn rotate(&mut self, angle: f64) {
     unsafe {
-      cairo_rotate(self.opaque, angle);
+      ffi::cairo_rotate(self.opaque, angle);
     }
   }
 
@@ -1500,7 +1660,7 @@ impl Cairo {
   /// Since 1.0
   pub fn transform(&mut self, matrix: &matrix::Matrix) {
     unsafe {
-      cairo_transform(self.opaque, matrix);
+      ffi::cairo_transform(self.opaque, matrix as *matrix::Matrix as *ffi::cairo_matrix_t);
     }
   }
 
@@ -1513,7 +1673,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_matrix(&mut self, matrix: &matrix::Matrix) {
     unsafe {
-      cairo_set_matrix(self.opaque, matrix);
+      ffi::cairo_set_matrix(self.opaque, matrix as *matrix::Matrix as *ffi::cairo_matrix_t);
     }
   }
 
@@ -1527,7 +1687,7 @@ impl Cairo {
   pub fn get_matrix(&mut self) -> matrix::Matrix {
     unsafe {
       let mut matrix:matrix::Matrix = std::intrinsics::init();
-      cairo_get_matrix(self.opaque, &mut matrix);
+      ffi::cairo_get_matrix(self.opaque, &mut matrix as *mut matrix::Matrix as *mut ffi::cairo_matrix_t);
       return matrix;
     }
   }
@@ -1539,7 +1699,7 @@ impl Cairo {
   /// Since 1.0
   pub fn identity_matrix(&mut self) {
     unsafe {
-      cairo_identity_matrix(self.opaque);
+      ffi::cairo_identity_matrix(self.opaque);
     }
   }
 
@@ -1557,7 +1717,7 @@ impl Cairo {
     unsafe {
       let mut x = x_r;
       let mut y = y_r;
-      cairo_user_to_device(self.opaque, &mut x, &mut y);
+      ffi::cairo_user_to_device(self.opaque, &mut x, &mut y);
       return (x, y);
     }
   }
@@ -1575,7 +1735,7 @@ impl Cairo {
     unsafe {
       let mut dx = dx_r;
       let mut dy = dy_r;
-      cairo_user_to_device_distance(self.opaque, &mut dx, &mut dy);
+      ffi::cairo_user_to_device_distance(self.opaque, &mut dx, &mut dy);
       return (dx, dy);
     }
   }
@@ -1593,7 +1753,7 @@ impl Cairo {
     unsafe {
       let mut x = x_r;
       let mut y = y_r;
-      cairo_device_to_user(self.opaque, &mut x, &mut y);
+      ffi::cairo_device_to_user(self.opaque, &mut x, &mut y);
       return (x, y);
     }
   }
@@ -1612,7 +1772,7 @@ impl Cairo {
     unsafe {
       let mut dx = dx_r;
       let mut dy = dy_r;
-      cairo_device_to_user_distance(self.opaque, &mut dx, &mut dy);
+      ffi::cairo_device_to_user_distance(self.opaque, &mut dx, &mut dy);
       return (dx, dy);
     }
   }
@@ -1627,7 +1787,7 @@ impl Cairo {
   pub fn select_font_face(&mut self, family: &str, slant: font::slant::Slant, weight: font::weight::Weight) {
     unsafe {
       use std::c_str::ToCStr;
-      cairo_select_font_face(self.opaque, family.to_c_str().unwrap(), slant, weight);
+      ffi::cairo_select_font_face(self.opaque, family.to_c_str().unwrap(), slant as ffi::cairo_font_slant_t, weight as ffi::cairo_font_weight_t);
     }
   }
 
@@ -1642,7 +1802,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_font_size(&mut self, size: f64) {
     unsafe {
-      cairo_set_font_size(self.opaque, size);
+      ffi::cairo_set_font_size(self.opaque, size);
     }
   }
 
@@ -1655,7 +1815,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_font_matrix(&mut self, size: &matrix::Matrix) {
     unsafe {
-      cairo_set_font_matrix(self.opaque, size);
+      ffi::cairo_set_font_matrix(self.opaque, size as *matrix::Matrix as *ffi::cairo_matrix_t);
     }
   }
 
@@ -1669,7 +1829,7 @@ impl Cairo {
   pub fn get_font_matrix(&mut self) -> matrix::Matrix {
     unsafe {
       let mut matrix:matrix::Matrix = std::intrinsics::init();
-      cairo_get_font_matrix(self.opaque, &mut matrix);
+      ffi::cairo_get_font_matrix(self.opaque, &mut matrix as *mut matrix::Matrix as *mut ffi::cairo_matrix_t);
       return matrix;
     }
   }
@@ -1684,7 +1844,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_font_options(&mut self, options: font::Options) {
     unsafe {
-      cairo_set_font_options(self.opaque, options);
+      ffi::cairo_set_font_options(self.opaque, options.opaque);
     }
   }
 
@@ -1695,9 +1855,9 @@ impl Cairo {
   /// options : a font::Options object into which to store the retrieved options. All existing values are overwritten
   ///
   /// Since 1.0
-  pub fn get_font_options(&mut self, options: font::Options) {
+  pub fn get_font_options(&mut self, options: &mut font::Options) {
     unsafe {
-      cairo_get_font_options(self.opaque, options);
+      ffi::cairo_get_font_options(self.opaque, options.opaque);
     }
   }
 
@@ -1710,7 +1870,7 @@ impl Cairo {
   /// Since 1.0
   pub fn set_font_face(&mut self, font_face: font::FontFace) {
     unsafe {
-      cairo_set_font_face(self.opaque, font_face);
+      ffi::cairo_set_font_face(self.opaque, font_face.opaque);
     }
   }
 
@@ -1724,7 +1884,7 @@ impl Cairo {
   /// Since 1.0
   pub fn get_font_face(&mut self) -> font::FontFace {
     unsafe {
-      let foreign_result = cairo_get_font_face(self.opaque);
+      let foreign_result = font::FontFace { opaque: ffi::cairo_get_font_face(self.opaque) };
       return foreign_result;
     }
   }
@@ -1738,7 +1898,7 @@ impl Cairo {
   /// Since 1.2
   pub fn set_scaled_font(&mut self, scaled_font: font::ScaledFont) {
     unsafe {
-      cairo_set_scaled_font(self.opaque, scaled_font);
+      ffi::cairo_set_scaled_font(self.opaque, scaled_font.opaque);
     }
   }
 
@@ -1752,7 +1912,7 @@ impl Cairo {
   /// Since 1.4
   pub fn get_scaled_font(&mut self) -> font::ScaledFont {
     unsafe {
-      let foreign_result = cairo_get_scaled_font(self.opaque);
+      let foreign_result = font::ScaledFont { opaque: ffi::cairo_get_scaled_font(self.opaque) };
       return foreign_result;
     }
   }
@@ -1772,7 +1932,7 @@ impl Cairo {
   /// Since 1.0
   pub fn show_text(&mut self, utf8: &str) {
     unsafe {
-      cairo_show_text(self.opaque, utf8.to_c_str().unwrap());
+      ffi::cairo_show_text(self.opaque, utf8.to_c_str().unwrap());
     }
   }
 
@@ -1788,7 +1948,7 @@ impl Cairo {
   /// Since 1.0
   pub fn show_glyphs(&mut self, glyphs: &[font::Glyph]) {
     unsafe {
-      cairo_show_glyphs(self.opaque, glyphs.as_ptr(), glyphs.len() as i32);
+      ffi::cairo_show_glyphs(self.opaque, glyphs.as_ptr() as *font::Glyph as *ffi::cairo_glyph_t, glyphs.len() as i32);
     }
   }
 
@@ -1819,7 +1979,7 @@ impl Cairo {
   /// Since 1.8
   pub fn show_text_glyphs(&mut self, utf8: &str, glyphs: &[font::Glyph], clusters: &[font::Cluster], cluster_flags: font::cluster_flags::ClusterFlags) {
     unsafe {
-      cairo_show_text_glyphs(self.opaque, utf8.to_c_str().unwrap(), -1, glyphs.as_ptr(), glyphs.len() as i32, clusters.as_ptr(), clusters.len() as i32, cluster_flags);
+      ffi::cairo_show_text_glyphs(self.opaque, utf8.to_c_str().unwrap(), -1, glyphs.as_ptr() as *font::Glyph as *ffi::cairo_glyph_t, glyphs.len() as i32, clusters.as_ptr() as *font::Cluster as *ffi::cairo_text_cluster_t, clusters.len() as i32, cluster_flags as ffi::cairo_text_cluster_flags_t);
     }
   }
 
@@ -1833,7 +1993,7 @@ impl Cairo {
   pub fn font_extents(&mut self) -> font::FontExtents {
     unsafe {
       let mut extents:font::FontExtents = std::intrinsics::init();
-      cairo_font_extents(self.opaque, &mut extents);
+      ffi::cairo_font_extents(self.opaque, &mut extents as *mut font::FontExtents as *mut ffi::cairo_font_extents_t);
       return extents;
     }
   }
@@ -1852,7 +2012,7 @@ impl Cairo {
   pub fn text_extents(&mut self, utf8: &str) -> font::TextExtents {
     unsafe {
       let mut extents:font::TextExtents = std::intrinsics::init();
-      cairo_text_extents(self.opaque, utf8.to_c_str().unwrap(), &mut extents);
+      ffi::cairo_text_extents(self.opaque, utf8.to_c_str().unwrap(), &mut extents as *mut font::TextExtents as *mut ffi::cairo_text_extents_t);
       return extents;
     }
   }
@@ -1873,139 +2033,169 @@ impl Cairo {
   pub fn glyph_extents(&mut self, glyphs: &[font::Glyph]) -> font::TextExtents {
     unsafe {
       let mut extents:font::TextExtents = std::intrinsics::init();
-      cairo_glyph_extents(self.opaque, glyphs.as_ptr(), glyphs.len() as i32, &mut extents);
+      ffi::cairo_glyph_extents(self.opaque, glyphs.as_ptr() as *font::Glyph as *ffi::cairo_glyph_t, glyphs.len() as i32, &mut extents as *mut font::TextExtents as *mut ffi::cairo_text_extents_t);
       return extents;
     }
   }
-}
 
-extern {
-  fn cairo_create(surface: *mut libc::c_void) -> Cairo;
-  fn cairo_status(self_value: *mut libc::c_void) -> Status;
-  fn cairo_save(self_value: *mut libc::c_void);
-  fn cairo_restore(self_value: *mut libc::c_void);
-  fn cairo_get_target(self_value: *mut libc::c_void) -> surface::Surface;
-  fn cairo_push_group(self_value: *mut libc::c_void);
-  fn cairo_push_group_with_content(self_value: *mut libc::c_void, content: surface::content::Content);
-  fn cairo_pop_group(self_value: *mut libc::c_void) -> pattern::Pattern;
-  fn cairo_pop_group_to_source(self_value: *mut libc::c_void);
-  fn cairo_get_group_target(self_value: *mut libc::c_void) -> surface::Surface;
-  fn cairo_set_source_rgb(self_value: *mut libc::c_void, red: f64, green: f64, blue: f64);
-  fn cairo_set_source_rgba(self_value: *mut libc::c_void, red: f64, green: f64, blue: f64, alpha: f64);
-  fn cairo_set_source(self_value: *mut libc::c_void, source: *mut libc::c_void);
-  fn cairo_set_source_surface(self_value: *mut libc::c_void, surface: *mut libc::c_void, x: f64, y: f64);
-  fn cairo_get_source(self_value: *mut libc::c_void) -> pattern::Pattern;
-  fn cairo_set_antialias(self_value: *mut libc::c_void, antialias: antialias::Antialias);
-  fn cairo_get_antialias(self_value: *mut libc::c_void) -> antialias::Antialias;
-  fn cairo_set_dash(self_value: *mut libc::c_void, dashes: *f64, dashes_length: i32, offset: f64);
-  fn cairo_get_dash_count(self_value: *mut libc::c_void) -> i32;
-  fn cairo_get_dash(self_value: *mut libc::c_void, dashes: *mut f64, offset: *mut f64);
-  fn cairo_set_fill_rule(self_value: *mut libc::c_void, fill_rule: fill_rule::FillRule);
-  fn cairo_get_fill_rule(self_value: *mut libc::c_void) -> fill_rule::FillRule;
-  fn cairo_set_line_cap(self_value: *mut libc::c_void, line_cap: line_cap::LineCap);
-  fn cairo_get_line_cap(self_value: *mut libc::c_void) -> line_cap::LineCap;
-  fn cairo_set_line_join(self_value: *mut libc::c_void, line_join: line_join::LineJoin);
-  fn cairo_get_line_join(self_value: *mut libc::c_void) -> line_join::LineJoin;
-  fn cairo_set_line_width(self_value: *mut libc::c_void, width: f64);
-  fn cairo_get_line_width(self_value: *mut libc::c_void) -> f64;
-  fn cairo_set_miter_limit(self_value: *mut libc::c_void, limit: f64);
-  fn cairo_get_miter_limit(self_value: *mut libc::c_void) -> f64;
-  fn cairo_set_operator(self_value: *mut libc::c_void, operator: operator::Operator);
-  fn cairo_get_operator(self_value: *mut libc::c_void) -> operator::Operator;
-  fn cairo_set_tolerance(self_value: *mut libc::c_void, tolerance: f64);
-  fn cairo_get_tolerance(self_value: *mut libc::c_void) -> f64;
-  fn cairo_clip(self_value: *mut libc::c_void);
-  fn cairo_clip_preserve(self_value: *mut libc::c_void);
-  fn cairo_clip_extents(self_value: *mut libc::c_void, x1: *mut f64, y1: *mut f64, x2: *mut f64, y2: *mut f64);
-  fn cairo_in_clip(self_value: *mut libc::c_void, x: f64, y: f64) -> i32;
-  fn cairo_reset_clip(self_value: *mut libc::c_void);
-  fn cairo_fill(self_value: *mut libc::c_void);
-  fn cairo_fill_preserve(self_value: *mut libc::c_void);
-  fn cairo_fill_extents(self_value: *mut libc::c_void, x1: *mut f64, y1: *mut f64, x2: *mut f64, y2: *mut f64);
-  fn cairo_in_fill(self_value: *mut libc::c_void, x: f64, y: f64) -> i32;
-  fn cairo_mask(self_value: *mut libc::c_void, pattern: *mut libc::c_void);
-  fn cairo_mask_surface(self_value: *mut libc::c_void, surface: *mut libc::c_void, surface_x: f64, surface_y: f64);
-  fn cairo_paint(self_value: *mut libc::c_void);
-  fn cairo_paint_with_alpha(self_value: *mut libc::c_void, alpha: f64);
-  fn cairo_stroke(self_value: *mut libc::c_void);
-  fn cairo_stroke_preserve(self_value: *mut libc::c_void);
-  fn cairo_stroke_extents(self_value: *mut libc::c_void, x1: *mut f64, y1: *mut f64, x2: *mut f64, y2: *mut f64);
-  fn cairo_in_stroke(self_value: *mut libc::c_void, x: f64, y: f64) -> i32;
-  fn cairo_copy_page(self_value: *mut libc::c_void);
-  fn cairo_show_page(self_value: *mut libc::c_void);
-  fn cairo_get_reference_count(self_value: *mut libc::c_void) -> i32;
-  fn cairo_copy_path(self_value: *mut libc::c_void) -> path::Path;
-  fn cairo_copy_path_flat(self_value: *mut libc::c_void) -> path::Path;
-  fn cairo_append_path(self_value: *mut libc::c_void, path: *libc::c_void);
-  fn cairo_has_current_point(self_value: *mut libc::c_void) -> i32;
-  fn cairo_get_current_point(self_value: *mut libc::c_void, x: *mut f64, y: *mut f64);
-  fn cairo_new_path(self_value: *mut libc::c_void);
-  fn cairo_new_sub_path(self_value: *mut libc::c_void);
-  fn cairo_close_path(self_value: *mut libc::c_void);
-  fn cairo_arc(self_value: *mut libc::c_void, xc: f64, yc: f64, radius: f64, angle1: f64, angle2: f64);
-  fn cairo_arc_negative(self_value: *mut libc::c_void, xc: f64, yc: f64, radius: f64, angle1: f64, angle2: f64);
-  fn cairo_curve_to(self_value: *mut libc::c_void, x1: f64, y1: f64, x2: f64, y2: f64, x3: f64, y3: f64);
-  fn cairo_line_to(self_value: *mut libc::c_void, x: f64, y: f64);
-  fn cairo_move_to(self_value: *mut libc::c_void, x: f64, y: f64);
-  fn cairo_rectangle(self_value: *mut libc::c_void, x: f64, y: f64, width: f64, height: f64);
-  fn cairo_glyph_path(self_value: *mut libc::c_void, glyphs: *font::Glyph, glyphs_length: i32);
-  fn cairo_text_path(self_value: *mut libc::c_void, text_path: *libc::c_char);
-  fn cairo_rel_curve_to(self_value: *mut libc::c_void, dx1: f64, dy1: f64, dx2: f64, dy2: f64, dx3: f64, dy3: f64);
-  fn cairo_rel_line_to(self_value: *mut libc::c_void, dx: f64, dy: f64);
-  fn cairo_rel_move_to(self_value: *mut libc::c_void, dx: f64, dy: f64);
-  fn cairo_path_extents(self_value: *mut libc::c_void, x1: *mut f64, y1: *mut f64, x2: *mut f64, y2: *mut f64);
-  fn cairo_translate(self_value: *mut libc::c_void, tx: f64, ty: f64);
-  fn cairo_scale(self_value: *mut libc::c_void, sx: f64, sy: f64);
-  fn cairo_rotate(self_value: *mut libc::c_void, angle: f64);
-  fn cairo_transform(self_value: *mut libc::c_void, matrix: *matrix::Matrix);
-  fn cairo_set_matrix(self_value: *mut libc::c_void, matrix: *matrix::Matrix);
-  fn cairo_get_matrix(self_value: *mut libc::c_void, matrix: *mut matrix::Matrix);
-  fn cairo_identity_matrix(self_value: *mut libc::c_void);
-  fn cairo_user_to_device(self_value: *mut libc::c_void, x: &mut f64, y: &mut f64);
-  fn cairo_user_to_device_distance(self_value: *mut libc::c_void, dx: &mut f64, dy: &mut f64);
-  fn cairo_device_to_user(self_value: *mut libc::c_void, x: &mut f64, y: &mut f64);
-  fn cairo_device_to_user_distance(self_value: *mut libc::c_void, dx: &mut f64, dy: &mut f64);
-  fn cairo_select_font_face(self_value: *mut libc::c_void, family: *libc::c_char, slant: font::slant::Slant, weight: font::weight::Weight);
-  fn cairo_set_font_size(self_value: *mut libc::c_void, size: f64);
-  fn cairo_set_font_matrix(self_value: *mut libc::c_void, size: *matrix::Matrix);
-  fn cairo_get_font_matrix(self_value: *mut libc::c_void, matrix: *mut matrix::Matrix);
-  fn cairo_set_font_options(self_value: *mut libc::c_void, options: font::Options);
-  fn cairo_get_font_options(self_value: *mut libc::c_void, options: font::Options);
-  fn cairo_set_font_face(self_value: *mut libc::c_void, font_face: font::FontFace);
-  fn cairo_get_font_face(self_value: *mut libc::c_void) -> font::FontFace;
-  fn cairo_set_scaled_font(self_value: *mut libc::c_void, scaled_font: font::ScaledFont);
-  fn cairo_get_scaled_font(self_value: *mut libc::c_void) -> font::ScaledFont;
-  fn cairo_show_text(self_value: *mut libc::c_void, utf8: *libc::c_char);
-  fn cairo_show_glyphs(self_value: *mut libc::c_void, glyphs: *font::Glyph, glyphs_length: i32);
-  fn cairo_show_text_glyphs(self_value: *mut libc::c_void, utf8: *libc::c_char, utf8_len: i32, glyphs: *font::Glyph, glyphs_length: i32, clusters: *font::Cluster, clusters_length: i32, cluster_flags: font::cluster_flags::ClusterFlags);
-  fn cairo_font_extents(self_value: *mut libc::c_void, extents: *mut font::FontExtents);
-  fn cairo_text_extents(self_value: *mut libc::c_void, utf8: *libc::c_char, extents: *mut font::TextExtents);
-  fn cairo_glyph_extents(self_value: *mut libc::c_void, glyphs: *font::Glyph, glyphs_length: i32, extents: *mut font::TextExtents);
+  /// Marks the beginning of the tag_name structure. Call cairo_tag_end() with the same tag_name to mark the end of the structure.
+  ///
+  /// The attributes string is of the form "key1=value2 key2=value2 ...", see tag::Attributes for building it. Tags can be nested, but an inner tag must be ended before the outer one.
+  ///
+  /// cr : a cairo context
+  ///
+  /// tag_name : tag name
+  ///
+  /// attributes : tag attributes
+  ///
+  /// Since 1.16
+  #[cfg(feature = "v1_16")]
+  pub fn tag_begin(&mut self, tag_name: tag::Tag, attributes: &tag::Attributes) {
+    unsafe {
+      use std::c_str::ToCStr;
+      ffi::cairo_tag_begin(self.opaque, tag_name.name().to_c_str().unwrap(), format!("{}", attributes).to_c_str().unwrap());
+    }
+  }
+
+  /// Marks the end of the tag_name structure.
+  ///
+  /// cr : a cairo context
+  ///
+  /// tag_name : tag name
+  ///
+  /// Since 1.16
+  #[cfg(feature = "v1_16")]
+  pub fn tag_end(&mut self, tag_name: tag::Tag) {
+    unsafe {
+      use std::c_str::ToCStr;
+      ffi::cairo_tag_end(self.opaque, tag_name.name().to_c_str().unwrap());
+    }
+  }
+
+  /// Begins the tag_name structure and returns a guard which ends it when it goes out of scope, see tag::TagGuard.
+  ///
+  /// cr : a cairo context
+  ///
+  /// tag_name : tag name
+  ///
+  /// attributes : tag attributes
+  ///
+  /// Returns : the guard, through which the context is used while the tag is open
+  ///
+  /// Since 1.16
+  #[cfg(feature = "v1_16")]
+  pub fn tag<'a>(&'a mut self, tag_name: tag::Tag, attributes: &tag::Attributes) -> tag::TagGuard<'a> {
+    tag::TagGuard::begin(self, tag_name, attributes)
+  }
 }
 
+
 impl std::clone::Clone for Cairo {
   fn clone(&self) -> Cairo {
     unsafe {
-      let foreign_result = cairo_reference(self.opaque as *libc::c_void);
+      let foreign_result = Cairo { opaque: ffi::cairo_reference(self.opaque) };
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_reference(self_value: *libc::c_void) -> Cairo;
-}
 
 impl std::ops::Drop for Cairo {
   fn drop(&mut self) {
     unsafe {
-      cairo_destroy(self.opaque);
+      ffi::cairo_destroy(self.opaque);
+    }
+  }
+}
+
+
+/// Encodes the given cairo version into an integer. The numbers returned by cairo::version() are encoded in the same way, so encoded versions can be compared with the usual operators.
+///
+/// major : the major component of the version number
+///
+/// minor : the minor component of the version number
+///
+/// micro : the micro component of the version number
+///
+/// Returns : the encoded version.
+pub fn version_encode(major: i32, minor: i32, micro: i32) -> i32 {
+  major * 10000 + minor * 100 + micro
+}
+
+/// Returns the version of the cairo library encoded in a single integer as by cairo::version_encode(). This is the version of the library loaded at run-time, which may differ from the version the bindings were built against.
+///
+/// Since 1.0
+pub fn version() -> i32 {
+  unsafe {
+    let foreign_result = ffi::cairo_version();
+    return foreign_result;
+  }
+}
+
+/// Returns the version of the cairo library as a human-readable string of the form "X.Y.Z".
+///
+/// Since 1.0
+pub fn version_string() -> std::c_str::CString {
+  unsafe {
+    let foreign_result = ffi::cairo_version_string();
+    return std::c_str::CString::new(foreign_result, false);
+  }
+}
+
+/// Checks whether the cairo library loaded at run-time provides feature.
+///
+/// feature : a feature::Feature
+///
+/// Returns : true if the version of the library is at least the version that introduced feature.
+pub fn supports(feature: feature::Feature) -> bool {
+  version() >= feature.since()
+}
+
+/// The signature of the cairo_*_set_user_data() functions.
+type SetUserData = unsafe extern "C" fn(object: *mut libc::c_void, key: *ffi::cairo_user_data_key_t, user_data: *mut libc::c_void, destroy: ffi::cairo_destroy_func_t) -> ffi::cairo_status_t;
+
+/// The signature of the cairo_*_get_user_data() functions.
+type GetUserData = unsafe extern "C" fn(object: *mut libc::c_void, key: *ffi::cairo_user_data_key_t) -> *mut libc::c_void;
+
+/// Attaches data to object with set_user_data, or removes the data attached to key for None. The data is dropped when cairo calls the destroy notification, which happens when it is replaced or removed, or the object is destroyed.
+unsafe fn attach_user_data<T: 'static>(object: *mut libc::c_void, set_user_data: SetUserData, key: &'static user_data::Key, data: Option<T>) -> Status {
+  let key = key as *user_data::Key as *ffi::cairo_user_data_key_t;
+  match data {
+    None => {
+      let foreign_result = std::mem::transmute(set_user_data(object, key, std::ptr::mut_null(), destroy_user_data));
+      return foreign_result;
+    },
+    Some(data) => {
+      let data: Box<Box<std::any::Any + 'static>> = box (box data as Box<std::any::Any + 'static>);
+      let user_data: *mut libc::c_void = std::mem::transmute(data);
+      let status: Status = std::mem::transmute(set_user_data(object, key, user_data, destroy_user_data));
+      match status {
+        Success => {},
+        // Cairo only takes the data on success, otherwise it is dropped here.
+        _ => destroy_user_data(user_data)
+      }
+      return status;
     }
   }
 }
 
-extern {
-  fn cairo_destroy(self_value: *mut libc::c_void);
+/// Returns a clone of the data attached to object with attach_user_data(). The data is cloned because another reference to object may replace or remove it at any time.
+unsafe fn find_user_data<T: Clone + 'static>(object: *mut libc::c_void, get_user_data: GetUserData, key: &'static user_data::Key) -> Option<T> {
+  use std::any::AnyRefExt;
+  let user_data = get_user_data(object, key as *user_data::Key as *ffi::cairo_user_data_key_t);
+  if user_data.is_null() {
+    return None;
+  }
+  let data: &Box<std::any::Any + 'static> = std::mem::transmute(user_data);
+  return data.downcast_ref::<T>().map(|data| data.clone());
+}
+
+extern "C" fn destroy_user_data(user_data: *mut libc::c_void) {
+  unsafe {
+    if !user_data.is_null() {
+      let _data: Box<Box<std::any::Any + 'static>> = std::mem::transmute(user_data);
+    }
+  }
 }
 
 pub mod antialias;
@@ -2019,4 +2209,10 @@ pub mod region;
 pub mod font;
 pub mod surface;
 pub mod matrix;
+pub mod color;
+pub mod error;
+pub mod feature;
+pub mod shapes;
+pub mod tag;
+pub mod user_data;
 
@@ -1,11 +1,15 @@
 //! Describe how to render the endpoints of the path when stroking
 
+use std;
+use std::ascii::StrAsciiExt;
+
 /// Specifies how to render the endpoints of the path when stroking.
 /// 
 /// The default line cap style is CAIRO_LINE_CAP_BUTT.
 /// 
 /// Since 1.0
 #[repr(i32)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub enum LineCap {
   /// start(stop) the line exactly at the start(end) point (Since 1.0)
   Butt = 0,
@@ -15,3 +19,26 @@ pub enum LineCap {
   Square = 2
 }
 
+impl std::from_str::FromStr for LineCap {
+  /// Parses line_cap::LineCap, using the names of the SVG stroke-linecap property. Case and surrounding whitespace are ignored.
+  fn from_str(s: &str) -> Option<LineCap> {
+    match s.trim().to_ascii_lower().as_slice() {
+      "butt" => Some(Butt),
+      "round" => Some(Round),
+      "square" => Some(Square),
+      _ => None
+    }
+  }
+}
+
+impl std::fmt::Show for LineCap {
+  /// Formats line_cap::LineCap with the name accepted by from_str().
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let name = match *self {
+      Butt => "butt",
+      Round => "round",
+      Square => "square"
+    };
+    f.pad(name)
+  }
+}
@@ -1,11 +1,15 @@
 //! Descirbe how to render the junction of two lines
 
+use std;
+use std::ascii::StrAsciiExt;
+
 /// Specifies how to render the junction of two lines when stroking.
 /// 
 /// The default line join style is CAIRO_LINE_JOIN_MITER.
 /// 
 /// Since 1.0
 #[repr(i32)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub enum LineJoin {
   /// use a sharp (angled) corner, see cairo_set_miter_limit() (Since 1.0)
   Miter = 0,
@@ -14,3 +18,27 @@ pub enum LineJoin {
   /// use a cut-off join, the join is cut off at half the line width from the joint point (Since 1.0)
   Bevel = 2
 }
+
+impl std::from_str::FromStr for LineJoin {
+  /// Parses line_join::LineJoin, using the names of the SVG stroke-linejoin property. Case and surrounding whitespace are ignored.
+  fn from_str(s: &str) -> Option<LineJoin> {
+    match s.trim().to_ascii_lower().as_slice() {
+      "miter" => Some(Miter),
+      "round" => Some(Round),
+      "bevel" => Some(Bevel),
+      _ => None
+    }
+  }
+}
+
+impl std::fmt::Show for LineJoin {
+  /// Formats line_join::LineJoin with the name accepted by from_str().
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let name = match *self {
+      Miter => "miter",
+      Round => "round",
+      Bevel => "bevel"
+    };
+    f.pad(name)
+  }
+}
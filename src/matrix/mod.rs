@@ -1,6 +1,7 @@
 //! Generic matrix operations
 
 use std;
+use ffi;
 
 /// A matrix::Matrix holds an affine transformation, such as a scale, rotation, shear, or a combination of those. The transformation of a point (x, y) is given by:
 ///
@@ -10,19 +11,22 @@ use std;
 /// ```
 ///
 /// Since 1.0
+#[repr(C)]
+#[deriving(Clone, PartialEq, Show)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub struct Matrix {
   /// double xx; xx component of the affine transformation
-  xx: f64,
+  pub xx: f64,
   /// double yx; yx component of the affine transformation
-  yx: f64,
+  pub yx: f64,
   /// double xy; xy component of the affine transformation
-  xy: f64,
+  pub xy: f64,
   /// double yy; yy component of the affine transformation
-  yy: f64,
+  pub yy: f64,
   /// double x0; X translation component of the affine transformation
-  x0: f64,
+  pub x0: f64,
   /// double y0; Y translation component of the affine transformation
-  y0: f64
+  pub y0: f64
 }
 
 impl Matrix {
@@ -49,11 +53,7 @@ impl Matrix {
   ///
   /// Since 1.0
   pub fn new(xx: f64, yx: f64, xy: f64, yy: f64, x0: f64, y0: f64) -> Matrix {
-    unsafe {
-      let mut this:Matrix = std::intrinsics::init();
-      cairo_matrix_init(&mut this, xx, yx, xy, yy, x0, y0);
-      return this;
-    }
+    Matrix { xx: xx, yx: yx, xy: xy, yy: yy, x0: x0, y0: y0 }
   }
 
   /// Modifies matrix to be an identity transformation.
@@ -62,11 +62,7 @@ impl Matrix {
   ///
   /// Since 1.0
   pub fn identity() -> Matrix {
-    unsafe {
-      let mut this:Matrix = std::intrinsics::init();
-      cairo_matrix_init_identity(&mut this);
-      return this;
-    }
+    Matrix::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
   }
 
   /// Initializes matrix to a transformation that translates by tx and ty in the X and Y dimensions, respectively.
@@ -79,11 +75,7 @@ impl Matrix {
   ///
   /// Since 1.0
   pub fn for_translation(x0: f64, y0: f64) -> Matrix {
-    unsafe {
-      let mut this:Matrix = std::intrinsics::init();
-      cairo_matrix_init_translate(&mut this, x0, y0);
-      return this;
-    }
+    Matrix::new(1.0, 0.0, 0.0, 1.0, x0, y0)
   }
 
   /// Initializes matrix to a transformation that scales by sx and sy in the X and Y dimensions, respectively.
@@ -96,11 +88,7 @@ impl Matrix {
   ///
   /// Since 1.0
   pub fn for_scale(sx: f64, sy: f64) -> Matrix {
-    unsafe {
-      let mut this:Matrix = std::intrinsics::init();
-      cairo_matrix_init_scale(&mut this, sx, sy);
-      return this;
-    }
+    Matrix::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
   }
 
   /// Initialized matrix to a transformation that rotates by radians.
@@ -111,11 +99,8 @@ impl Matrix {
   ///
   /// Since 1.0
   pub fn for_rotation(radians: f64) -> Matrix {
-    unsafe {
-      let mut this:Matrix = std::intrinsics::init();
-      cairo_matrix_init_rotate(&mut this, radians);
-      return this;
-    }
+    let (sin, cos) = (radians.sin(), radians.cos());
+    Matrix::new(cos, sin, -sin, cos, 0.0, 0.0)
   }
 
   /// Multiplies the affine transformations in a and b together and stores the result in result. The effect of the resulting transformation is to first apply the transformation in a to the coordinates and then apply the transformation in b to the coordinates.
@@ -132,7 +117,7 @@ impl Matrix {
   pub fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
     unsafe {
       let mut this:Matrix = std::intrinsics::init();
-      cairo_matrix_multiply(&mut this, a, b);
+      ffi::cairo_matrix_multiply(&mut this as *mut Matrix as *mut ffi::cairo_matrix_t, a as *Matrix as *ffi::cairo_matrix_t, b as *Matrix as *ffi::cairo_matrix_t);
       return this;
     }
   }
@@ -148,7 +133,7 @@ impl Matrix {
   /// Since 1.0
   pub fn translate(&mut self, x0: f64, y0: f64) {
     unsafe {
-      cairo_matrix_translate(self, x0, y0);
+      ffi::cairo_matrix_translate(self as *mut Matrix as *mut ffi::cairo_matrix_t, x0, y0);
     }
   }
 
@@ -163,7 +148,7 @@ impl Matrix {
   /// Since 1.0
   pub fn scale(&mut self, sx: f64, sy: f64) {
     unsafe {
-      cairo_matrix_scale(self, sx, sy);
+      ffi::cairo_matrix_scale(self as *mut Matrix as *mut ffi::cairo_matrix_t, sx, sy);
     }
   }
 
@@ -176,7 +161,7 @@ impl Matrix {
   /// Since 1.0
   pub fn rotate(&mut self, radians: f64) {
     unsafe {
-      cairo_matrix_rotate(self, radians);
+      ffi::cairo_matrix_rotate(self as *mut Matrix as *mut ffi::cairo_matrix_t, radians);
     }
   }
 
@@ -200,7 +185,7 @@ impl Matrix {
     unsafe {
       let mut dx = dx_r;
       let mut dy = dy_r;
-      cairo_matrix_transform_distance(self, &mut dx, &mut dy);
+      ffi::cairo_matrix_transform_distance(self as *Matrix as *ffi::cairo_matrix_t, &mut dx, &mut dy);
       return (dx, dy);
     }
   }
@@ -218,7 +203,7 @@ impl Matrix {
     unsafe {
       let mut x = x_r;
       let mut y = y_r;
-      cairo_matrix_transform_point(self, &mut x, &mut y);
+      ffi::cairo_matrix_transform_point(self as *Matrix as *ffi::cairo_matrix_t, &mut x, &mut y);
       return (x, y);
     }
   }
@@ -232,24 +217,217 @@ impl Matrix {
   /// Since 1.0
   pub fn invert(&mut self) -> super::Status {
     unsafe {
-      let foreign_result = cairo_matrix_invert(self);
+      let foreign_result = std::mem::transmute(ffi::cairo_matrix_invert(self as *mut Matrix as *mut ffi::cairo_matrix_t));
       return foreign_result;
     }
   }
+
+  /// Creates a matrix from its components in the order xx, yx, xy, yy, x0, y0, which is the memory layout of cairo_matrix_t.
+  ///
+  /// components : the components of the affine transformation
+  pub fn from_array(components: [f64, ..6]) -> Matrix {
+    Matrix::new(components[0], components[1], components[2], components[3], components[4], components[5])
+  }
+
+  /// Returns the components of matrix in the order xx, yx, xy, yy, x0, y0, which is the memory layout of cairo_matrix_t.
+  ///
+  /// matrix : a matrix::Matrix
+  pub fn to_array(&self) -> [f64, ..6] {
+    [self.xx, self.yx, self.xy, self.yy, self.x0, self.y0]
+  }
+
+  /// Creates a matrix from the rows of a 3x2 matrix which transforms row vectors, [[xx, yx], [xy, yy], [x0, y0]]. This is the layout of mint::RowMatrix3x2 and euclid::Transform2D.
+  ///
+  /// rows : the rows of the 3x2 matrix
+  pub fn from_rows(rows: [[f64, ..2], ..3]) -> Matrix {
+    Matrix::new(rows[0][0], rows[0][1], rows[1][0], rows[1][1], rows[2][0], rows[2][1])
+  }
+
+  /// Returns the rows of the 3x2 matrix which transforms row vectors, [[xx, yx], [xy, yy], [x0, y0]]. This is the layout of mint::RowMatrix3x2 and euclid::Transform2D.
+  ///
+  /// matrix : a matrix::Matrix
+  pub fn to_rows(&self) -> [[f64, ..2], ..3] {
+    [[self.xx, self.yx], [self.xy, self.yy], [self.x0, self.y0]]
+  }
+
+  /// Creates a matrix from a 3x3 matrix which transforms column vectors, [[xx, xy, x0], [yx, yy, y0], [0, 0, 1]].
+  ///
+  /// rows : the rows of the 3x3 matrix
+  ///
+  /// Returns : the matrix, or None if the last row is not [0, 0, 1], in which case the transformation is not affine.
+  pub fn from_3x3(rows: [[f64, ..3], ..3]) -> Option<Matrix> {
+    if rows[2][0] != 0.0 || rows[2][1] != 0.0 || rows[2][2] != 1.0 {
+      return None;
+    }
+    Some(Matrix::new(rows[0][0], rows[1][0], rows[0][1], rows[1][1], rows[0][2], rows[1][2]))
+  }
+
+  /// Returns the 3x3 matrix which transforms column vectors, [[xx, xy, x0], [yx, yy, y0], [0, 0, 1]].
+  ///
+  /// matrix : a matrix::Matrix
+  pub fn to_3x3(&self) -> [[f64, ..3], ..3] {
+    [[self.xx, self.xy, self.x0], [self.yx, self.yy, self.y0], [0.0, 0.0, 1.0]]
+  }
+
+  /// Returns the inverse of matrix, see cairo_matrix_invert().
+  ///
+  /// matrix : a matrix::Matrix
+  ///
+  /// Returns : the inverse matrix, or None if matrix is degenerate.
+  pub fn inverse(&self) -> Option<Matrix> {
+    let mut inverse = self.clone();
+    match inverse.invert() {
+      super::Success => Some(inverse),
+      _ => None
+    }
+  }
+
+  /// Returns the determinant of the linear part of matrix. The matrix has no inverse when the determinant is 0.
+  ///
+  /// matrix : a matrix::Matrix
+  pub fn determinant(&self) -> f64 {
+    self.xx * self.yy - self.yx * self.xy
+  }
+
+  /// Splits matrix into a translation, rotation, skew and scale, see matrix::Decomposition.
+  ///
+  /// The rotation is taken from the transformed X axis, so a mirroring transformation gets a negative Y scale. A matrix which collapses the X axis gets no rotation and no skew.
+  ///
+  /// matrix : a matrix::Matrix
+  pub fn decompose(&self) -> Decomposition {
+    let scale_x = self.xx.hypot(self.yx);
+    if scale_x == 0.0 {
+      return Decomposition {
+        translate: (self.x0, self.y0),
+        rotation: 0.0,
+        skew: 0.0,
+        scale: (0.0, self.xy.hypot(self.yy))
+      };
+    }
+    let scale_y = self.determinant() / scale_x;
+    let shear = (self.xx * self.xy + self.yx * self.yy) / scale_x;
+    Decomposition {
+      translate: (self.x0, self.y0),
+      rotation: self.yx.atan2(self.xx),
+      skew: if scale_y == 0.0 { 0.0 } else { shear / scale_y },
+      scale: (scale_x, scale_y)
+    }
+  }
+
+  /// Checks whether all components of both matrices differ by at most epsilon.
+  ///
+  /// matrix : a matrix::Matrix
+  ///
+  /// other : another matrix::Matrix
+  ///
+  /// epsilon : the largest difference allowed between two components
+  pub fn approx_eq(&self, other: &Matrix, epsilon: f64) -> bool {
+    (self.xx - other.xx).abs() <= epsilon &&
+    (self.yx - other.yx).abs() <= epsilon &&
+    (self.xy - other.xy).abs() <= epsilon &&
+    (self.yy - other.yy).abs() <= epsilon &&
+    (self.x0 - other.x0).abs() <= epsilon &&
+    (self.y0 - other.y0).abs() <= epsilon
+  }
 }
 
-extern {
-  fn cairo_matrix_init(this: *mut Matrix, xx: f64, yx: f64, xy: f64, yy: f64, x0: f64, y0: f64);
-  fn cairo_matrix_init_identity(this: *mut Matrix);
-  fn cairo_matrix_init_translate(this: *mut Matrix, x0: f64, y0: f64);
-  fn cairo_matrix_init_scale(this: *mut Matrix, sx: f64, sy: f64);
-  fn cairo_matrix_init_rotate(this: *mut Matrix, radians: f64);
-  fn cairo_matrix_multiply(this: *mut Matrix, a: *Matrix, b: *Matrix);
-  fn cairo_matrix_translate(self_value: *mut Matrix, x0: f64, y0: f64);
-  fn cairo_matrix_scale(self_value: *mut Matrix, sx: f64, sy: f64);
-  fn cairo_matrix_rotate(self_value: *mut Matrix, radians: f64);
-  fn cairo_matrix_transform_distance(self_value: *Matrix, dx: &mut f64, dy: &mut f64);
-  fn cairo_matrix_transform_point(self_value: *Matrix, x: &mut f64, y: &mut f64);
-  fn cairo_matrix_invert(self_value: *mut Matrix) -> super::Status;
+/// A matrix::Decomposition describes an affine transformation as a scale, followed by a skew, a rotation and a translation, as returned by matrix::Matrix::decompose().
+#[deriving(Clone, PartialEq, Show)]
+pub struct Decomposition {
+  /// the translation in the X and Y directions
+  pub translate: (f64, f64),
+  /// the angle of rotation, in radians
+  pub rotation: f64,
+  /// the skew factor along the X axis, so that (x, y) becomes (x + skew * y, y)
+  pub skew: f64,
+  /// the scale factors in the X and Y directions
+  pub scale: (f64, f64)
 }
 
+impl Decomposition {
+  /// Composes the transformation back into a matrix::Matrix.
+  pub fn to_matrix(&self) -> Matrix {
+    let (x0, y0) = self.translate;
+    let (sx, sy) = self.scale;
+    let (sin, cos) = (self.rotation.sin(), self.rotation.cos());
+    Matrix::new(cos * sx, sin * sx,
+                (cos * self.skew - sin) * sy, (sin * self.skew + cos) * sy,
+                x0, y0)
+  }
+}
+
+/// Implemented by the types that a matrix::Matrix can be multiplied with. Multiplying a matrix with a point (x, y) transforms the point, see cairo_matrix_transform_point().
+pub trait MatrixProduct<R> {
+  /// Computes matrix * self.
+  fn multiply_by(&self, matrix: &Matrix) -> R;
+}
+
+impl MatrixProduct<Matrix> for Matrix {
+  /// Computes matrix * self, the transformation that first applies self and then matrix. This is cairo_matrix_multiply(result, self, matrix), so (a * b) * point equals a * (b * point).
+  fn multiply_by(&self, matrix: &Matrix) -> Matrix {
+    Matrix::multiply(self, matrix)
+  }
+}
+
+impl MatrixProduct<(f64, f64)> for (f64, f64) {
+  /// Transforms the point by matrix.
+  fn multiply_by(&self, matrix: &Matrix) -> (f64, f64) {
+    let (x, y) = *self;
+    matrix.transform_point(x, y)
+  }
+}
+
+impl<R, T: MatrixProduct<R>> std::ops::Mul<T, R> for Matrix {
+  fn mul(&self, rhs: &T) -> R {
+    rhs.multiply_by(self)
+  }
+}
+
+
+
+#[cfg(test)]
+mod test {
+  use std::f64::consts::FRAC_PI_2;
+  use super::{Matrix, Decomposition};
+
+  #[test]
+  fn decompose_identity() {
+    let decomposition = Matrix::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0).decompose();
+    assert_eq!(decomposition, Decomposition { translate: (0.0, 0.0), rotation: 0.0, skew: 0.0, scale: (1.0, 1.0) });
+  }
+
+  #[test]
+  fn decompose_rotation() {
+    let decomposition = Matrix::new(0.0, 1.0, -1.0, 0.0, 0.0, 0.0).decompose();
+    assert!((decomposition.rotation - FRAC_PI_2).abs() < 1e-12);
+    assert_eq!(decomposition.skew, 0.0);
+    assert_eq!(decomposition.scale, (1.0, 1.0));
+  }
+
+  #[test]
+  fn decompose_mirror() {
+    let decomposition = Matrix::new(1.0, 0.0, 0.0, -1.0, 3.0, 4.0).decompose();
+    assert_eq!(decomposition.translate, (3.0, 4.0));
+    assert_eq!(decomposition.rotation, 0.0);
+    assert_eq!(decomposition.scale, (1.0, -1.0));
+  }
+
+  #[test]
+  fn decompose_collapsed_x_axis() {
+    let decomposition = Matrix::new(0.0, 0.0, 3.0, 4.0, 1.0, 2.0).decompose();
+    assert_eq!(decomposition, Decomposition { translate: (1.0, 2.0), rotation: 0.0, skew: 0.0, scale: (0.0, 5.0) });
+  }
+
+  #[test]
+  fn decompose_round_trip() {
+    let matrix = Matrix::new(2.0, 1.0, -0.5, 3.0, 10.0, -5.0);
+    assert!(matrix.decompose().to_matrix().approx_eq(&matrix, 1e-9));
+  }
+
+  #[test]
+  fn determinant_and_inverse() {
+    let matrix = Matrix::new(2.0, 0.0, 0.0, 4.0, 1.0, 1.0);
+    assert_eq!(matrix.determinant(), 8.0);
+    assert!(Matrix::new(1.0, 2.0, 2.0, 4.0, 0.0, 0.0).inverse().is_none());
+  }
+}
@@ -1,5 +1,8 @@
 //! Describe composition order for drawing operations
 
+use std;
+use std::ascii::StrAsciiExt;
+
 /// operator::Operator is used to set the compositing operator for all cairo drawing operations.
 ///
 /// The default operator is CAIRO_OPERATOR_OVER.
@@ -10,6 +13,7 @@
 ///
 /// Since 1.0
 #[repr(i32)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub enum Operator {
   /// clear destination layer (bounded) (Since 1.0)
   Clear = 0,
@@ -71,3 +75,109 @@ pub enum Operator {
   HSLLuminosity = 28
 }
 
+impl std::from_str::FromStr for Operator {
+  /// Parses operator::Operator, using the names of the CSS mix-blend-mode property for the blend operators, such as "multiply" and "color-dodge", and short Porter-Duff names such as "over" and "dest-in" for the others. The names of the canvas globalCompositeOperation property, such as "source-over", "copy" and "lighter", are accepted as well. Case and surrounding whitespace are ignored.
+  fn from_str(s: &str) -> Option<Operator> {
+    match s.trim().to_ascii_lower().as_slice() {
+      "clear" => Some(Clear),
+      "source" | "copy" | "src" => Some(Source),
+      "over" | "source-over" | "src-over" | "normal" => Some(Over),
+      "in" | "source-in" | "src-in" => Some(In),
+      "out" | "source-out" | "src-out" => Some(Out),
+      "atop" | "source-atop" | "src-atop" => Some(Atop),
+      "dest" | "destination" | "dst" => Some(Dest),
+      "dest-over" | "destination-over" | "dst-over" => Some(DestOver),
+      "dest-in" | "destination-in" | "dst-in" => Some(DestIn),
+      "dest-out" | "destination-out" | "dst-out" => Some(DestOut),
+      "dest-atop" | "destination-atop" | "dst-atop" => Some(DestAtop),
+      "xor" => Some(Xor),
+      "add" | "lighter" | "plus" => Some(Add),
+      "saturate" => Some(Saturate),
+      "multiply" => Some(Multiply),
+      "screen" => Some(Screen),
+      "overlay" => Some(Overlay),
+      "darken" => Some(Darken),
+      "lighten" => Some(Lighten),
+      "color-dodge" => Some(ColorDodge),
+      "color-burn" => Some(ColorBurn),
+      "hard-light" => Some(HardLight),
+      "soft-light" => Some(SoftLight),
+      "difference" => Some(Difference),
+      "exclusion" => Some(Exclusion),
+      "hue" | "hsl-hue" => Some(HSLHue),
+      "saturation" | "hsl-saturation" => Some(HSLSaturation),
+      "color" | "hsl-color" => Some(HSLColor),
+      "luminosity" | "hsl-luminosity" => Some(HSLLuminosity),
+      _ => None
+    }
+  }
+}
+
+impl std::fmt::Show for Operator {
+  /// Formats operator::Operator with the name accepted by from_str().
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let name = match *self {
+      Clear => "clear",
+      Source => "source",
+      Over => "over",
+      In => "in",
+      Out => "out",
+      Atop => "atop",
+      Dest => "dest",
+      DestOver => "dest-over",
+      DestIn => "dest-in",
+      DestOut => "dest-out",
+      DestAtop => "dest-atop",
+      Xor => "xor",
+      Add => "add",
+      Saturate => "saturate",
+      Multiply => "multiply",
+      Screen => "screen",
+      Overlay => "overlay",
+      Darken => "darken",
+      Lighten => "lighten",
+      ColorDodge => "color-dodge",
+      ColorBurn => "color-burn",
+      HardLight => "hard-light",
+      SoftLight => "soft-light",
+      Difference => "difference",
+      Exclusion => "exclusion",
+      HSLHue => "hue",
+      HSLSaturation => "saturation",
+      HSLColor => "color",
+      HSLLuminosity => "luminosity"
+    };
+    f.pad(name)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::from_str::from_str;
+  use super::Operator;
+
+  fn parse(s: &str) -> Option<String> {
+    from_str::<Operator>(s).map(|operator| format!("{}", operator))
+  }
+
+  #[test]
+  fn names_round_trip() {
+    let names = ["clear", "source", "over", "in", "out", "atop", "dest", "dest-over", "dest-in", "dest-out", "dest-atop", "xor", "add", "saturate",
+                 "multiply", "screen", "overlay", "darken", "lighten", "color-dodge", "color-burn", "hard-light", "soft-light", "difference", "exclusion",
+                 "hue", "saturation", "color", "luminosity"];
+    for name in names.iter() {
+      assert_eq!(parse(*name), Some(name.to_string()));
+    }
+  }
+
+  #[test]
+  fn aliases() {
+    assert_eq!(parse("source-over"), Some("over".to_string()));
+    assert_eq!(parse("copy"), Some("source".to_string()));
+    assert_eq!(parse("destination-out"), Some("dest-out".to_string()));
+    assert_eq!(parse("lighter"), Some("add".to_string()));
+    assert_eq!(parse("hsl-luminosity"), Some("luminosity".to_string()));
+    assert_eq!(parse("  Color-Dodge "), Some("color-dodge".to_string()));
+    assert_eq!(parse("plus-lighter"), None);
+  }
+}
@@ -2,6 +2,7 @@
 
 use std;
 use libc;
+use ffi;
 
 ///  A data structure for holding a path. This data structure serves as the return value for cairo_copy_path() and cairo_copy_path_flat() as well the input value for cairo_append_path().
 ///
@@ -21,15 +22,90 @@ pub struct Path {
   pub opaque: *mut libc::c_void
 }
 
-impl std::ops::Drop for Path {
-  fn drop(&mut self) {
+/// path::Segment describes one portion of a path, decoded from the header and points of the cairo_path_data_t elements of a path::Path.
+///
+/// Since 1.0
+#[deriving(Clone, PartialEq, Show)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
+pub enum Segment {
+  /// A move-to operation to the point (x, y). (Since 1.0)
+  MoveTo(f64, f64),
+  /// A line-to operation to the point (x, y). (Since 1.0)
+  LineTo(f64, f64),
+  /// A curve-to operation from the current point to (x3, y3) using (x1, y1) and (x2, y2) as the control points. (Since 1.0)
+  CurveTo(f64, f64, f64, f64, f64, f64),
+  /// A close-path operation. (Since 1.0)
+  ClosePath
+}
+
+/// The header view of a cairo_path_data_t element.
+struct PathHeader {
+  data_type: i32,
+  length: i32
+}
+
+impl Path {
+  /// Returns the error status of the path. A path returned by cairo_copy_path() or cairo_mesh_pattern_get_path() has no data if this is not CAIRO_STATUS_SUCCESS.
+  ///
+  /// path : a path::Path
+  ///
+  /// Since 1.0
+  pub fn status(&self) -> super::Status {
     unsafe {
-      cairo_path_destroy(self.opaque);
+      let raw: &ffi::cairo_path_t = std::mem::transmute(self.opaque);
+      return std::mem::transmute(raw.status);
+    }
+  }
+
+  /// Decodes the elements of the path into a list of path::Segment values, in the order they appear in the path data.
+  ///
+  /// path : a path::Path
+  ///
+  /// Returns : the segments of the path, or an empty list if the path has no data.
+  pub fn segments(&self) -> Vec<Segment> {
+    unsafe {
+      let raw: &ffi::cairo_path_t = std::mem::transmute(self.opaque);
+      let mut segments = Vec::new();
+      let mut i = 0;
+      while i < raw.num_data {
+        let header: &PathHeader = std::mem::transmute(raw.data.offset(i as int));
+        let point = |n: i32| -> (f64, f64) {
+          let p = &*raw.data.offset((i + n) as int);
+          (p.x, p.y)
+        };
+        match header.data_type {
+          0 => {
+            let (x, y) = point(1);
+            segments.push(MoveTo(x, y));
+          },
+          1 => {
+            let (x, y) = point(1);
+            segments.push(LineTo(x, y));
+          },
+          2 => {
+            let (x1, y1) = point(1);
+            let (x2, y2) = point(2);
+            let (x3, y3) = point(3);
+            segments.push(CurveTo(x1, y1, x2, y2, x3, y3));
+          },
+          3 => segments.push(ClosePath),
+          _ => {}
+        }
+        if header.length <= 0 {
+          break;
+        }
+        i += header.length;
+      }
+      return segments;
     }
   }
 }
 
-extern {
-  fn cairo_path_destroy(self_value: *mut libc::c_void);
+impl std::ops::Drop for Path {
+  fn drop(&mut self) {
+    unsafe {
+      ffi::cairo_path_destroy(self.opaque as *mut ffi::cairo_path_t);
+    }
+  }
 }
 
@@ -0,0 +1,500 @@
+//! Parse CSS gradient functions into gradient patterns
+
+use std;
+use std::from_str::from_str;
+use std::ascii::StrAsciiExt;
+use std::f64::consts::PI;
+use super::Pattern;
+use super::extend;
+use super::gradient::{GradientBuilder, Linear, Radial};
+use super::super::color::Color;
+use super::super::matrix::Matrix;
+
+/// Creates a gradient pattern from a CSS linear-gradient(), radial-gradient(), repeating-linear-gradient() or repeating-radial-gradient() function, laid out for the box (x, y, width, height) the way a browser lays out a gradient background image.
+///
+/// Linear gradients accept an angle in deg, grad, rad or turn units, or a "to" side or corner. Radial gradients accept a circle or ellipse shape, a size keyword or explicit radii, and an "at" position. Colors may be hexadecimal, named, rgb(), rgba(), hsl() or hsla() colors, and color stop positions may be percentages or lengths in px. The repeating variants use extend::Repeat.
+///
+/// css : the gradient function, for example "linear-gradient(45deg, red 0%, blue 100%)"
+///
+/// x : the X coordinate of the box
+///
+/// y : the Y coordinate of the box
+///
+/// width : the width of the box
+///
+/// height : the height of the box
+///
+/// Returns : the newly created pattern::Pattern, or None if css is not a supported gradient function.
+pub fn gradient(css: &str, x: f64, y: f64, width: f64, height: f64) -> Option<Pattern> {
+  let css = css.trim().to_ascii_lower();
+  let css = css.as_slice();
+  let open = match css.find('(') {
+    Some(open) => open,
+    None => return None
+  };
+  if !css.ends_with(")") {
+    return None;
+  }
+  let name = css.slice_to(open).trim();
+  let args = split_top_level(css.slice(open + 1, css.len() - 1), ',');
+  let (repeating, name) = if name.starts_with("repeating-") {
+    (true, name.slice_from("repeating-".len()))
+  } else {
+    (false, name)
+  };
+  let pattern = match name {
+    "linear-gradient" => linear(args.as_slice(), x, y, width, height),
+    "radial-gradient" => radial(args.as_slice(), x, y, width, height),
+    _ => None
+  };
+  return match pattern {
+    Some(mut pattern) => {
+      if repeating {
+        pattern.set_extend(extend::Repeat);
+      }
+      Some(pattern)
+    },
+    None => None
+  };
+}
+
+/// Parses a CSS color: a hexadecimal color, a named color, or an rgb(), rgba(), hsl() or hsla() function using either the comma or the space separated syntax.
+///
+/// css : the color, for example "rgba(255, 128, 0, 50%)"
+///
+/// Returns : the color, or None if css is not a supported color.
+pub fn color(css: &str) -> Option<Color> {
+  let css = css.trim().to_ascii_lower();
+  let css = css.as_slice();
+  if css.starts_with("#") {
+    return Color::from_hex(css);
+  }
+  let open = match css.find('(') {
+    Some(open) => open,
+    None => return Color::from_name(css)
+  };
+  if !css.ends_with(")") {
+    return None;
+  }
+  let inner: String = css.slice(open + 1, css.len() - 1).chars()
+    .map(|c| if c == ',' || c == '/' { ' ' } else { c })
+    .collect();
+  let values: Vec<&str> = inner.as_slice().words().collect();
+  if values.len() != 3 && values.len() != 4 {
+    return None;
+  }
+  let alpha = if values.len() == 4 {
+    match fraction(values[3], 1.0) {
+      Some(alpha) => alpha,
+      None => return None
+    }
+  } else {
+    1.0
+  };
+  match css.slice_to(open).trim() {
+    "rgb" | "rgba" => {
+      match (fraction(values[0], 255.0), fraction(values[1], 255.0), fraction(values[2], 255.0)) {
+        (Some(red), Some(green), Some(blue)) => Some(Color::rgba(red, green, blue, alpha)),
+        _ => None
+      }
+    },
+    "hsl" | "hsla" => {
+      let hue = match angle(values[0]) {
+        Some(radians) => radians * 180.0 / PI,
+        None => match from_str::<f64>(values[0]) {
+          Some(degrees) => degrees,
+          None => return None
+        }
+      };
+      match (fraction(values[1], 100.0), fraction(values[2], 100.0)) {
+        (Some(saturation), Some(lightness)) => Some(Color::from_hsla(hue, saturation, lightness, alpha)),
+        _ => None
+      }
+    },
+    _ => None
+  }
+}
+
+/// Lays out a linear-gradient() for the box.
+fn linear(args: &[&str], x: f64, y: f64, width: f64, height: f64) -> Option<Pattern> {
+  if args.len() == 0 {
+    return None;
+  }
+  let (radians, stops) = match direction(args[0], width, height) {
+    Some(radians) => (radians, args.slice_from(1)),
+    None => (PI, args)
+  };
+  let (dx, dy) = (radians.sin(), -radians.cos());
+  let length = (width * dx).abs() + (height * dy).abs();
+  let stops = match color_stops(stops, length) {
+    Some(stops) => stops,
+    None => return None
+  };
+  let (first, last, stops) = normalize(stops, std::f64::NEG_INFINITY);
+  let x0 = x + width / 2.0 - dx * length / 2.0;
+  let y0 = y + height / 2.0 - dy * length / 2.0;
+  let geometry = Linear(x0 + dx * length * first, y0 + dy * length * first,
+                        x0 + dx * length * last, y0 + dy * length * last);
+  return Some(GradientBuilder::new(geometry, stops).build());
+}
+
+/// Lays out a radial-gradient() for the box.
+fn radial(args: &[&str], x: f64, y: f64, width: f64, height: f64) -> Option<Pattern> {
+  if args.len() == 0 {
+    return None;
+  }
+  let (shape, stops) = match radial_shape(args[0], x, y, width, height) {
+    Some(shape) => (shape, args.slice_from(1)),
+    None => match radial_shape("", x, y, width, height) {
+      Some(shape) => (shape, args),
+      None => return None
+    }
+  };
+  let (cx, cy, rx, ry) = shape;
+  let rx = rx.max(1e-6);
+  let ry = ry.max(1e-6);
+  let stops = match color_stops(stops, rx) {
+    Some(stops) => stops,
+    None => return None
+  };
+  let (first, last, stops) = normalize(stops, 0.0);
+  if rx == ry {
+    let geometry = Radial(cx, cy, rx * first, cx, cy, rx * last);
+    return Some(GradientBuilder::new(geometry, stops).build());
+  }
+  let mut pattern = GradientBuilder::new(Radial(0.0, 0.0, first, 0.0, 0.0, last), stops).build();
+  pattern.set_matrix(Matrix::multiply(&Matrix::for_translation(-cx, -cy), &Matrix::for_scale(1.0 / rx, 1.0 / ry)));
+  return Some(pattern);
+}
+
+/// Parses the direction of a linear-gradient(), as an angle or a "to" side or corner. Returns the CSS angle in radians, clockwise from the top.
+fn direction(arg: &str, width: f64, height: f64) -> Option<f64> {
+  if !arg.starts_with("to ") {
+    return angle(arg);
+  }
+  let (mut sx, mut sy) = (0.0, 0.0);
+  for word in arg.slice_from(3).words() {
+    match word {
+      "left" if sx == 0.0 => sx = -1.0,
+      "right" if sx == 0.0 => sx = 1.0,
+      "top" if sy == 0.0 => sy = -1.0,
+      "bottom" if sy == 0.0 => sy = 1.0,
+      _ => return None
+    }
+  }
+  if sx == 0.0 && sy == 0.0 {
+    return None;
+  }
+  // Towards a corner the gradient line is perpendicular to the diagonal between the two neighbouring corners.
+  let (dx, dy) = if sx == 0.0 || sy == 0.0 { (sx, sy) } else { (sx * height, sy * width) };
+  return Some(dx.atan2(-dy));
+}
+
+/// Parses a CSS angle in deg, grad, rad or turn units into radians. A unitless zero is accepted.
+fn angle(arg: &str) -> Option<f64> {
+  let units = [("deg", PI / 180.0), ("grad", PI / 200.0), ("rad", 1.0), ("turn", 2.0 * PI)];
+  for &(unit, scale) in units.iter() {
+    if arg.ends_with(unit) {
+      return from_str::<f64>(arg.slice_to(arg.len() - unit.len())).map(|value| value * scale);
+    }
+  }
+  match from_str::<f64>(arg) {
+    Some(value) if value == 0.0 => Some(0.0),
+    _ => None
+  }
+}
+
+/// Parses the shape, size and position of a radial-gradient() as (cx, cy, rx, ry). An empty argument gives the default farthest-corner ellipse at the center of the box.
+fn radial_shape(arg: &str, x: f64, y: f64, width: f64, height: f64) -> Option<(f64, f64, f64, f64)> {
+  let words: Vec<&str> = arg.words().collect();
+  let at = words.iter().position(|word| *word == "at").unwrap_or(words.len());
+  let (cx, cy) = if at < words.len() {
+    match position(words.slice_from(at + 1), width, height) {
+      Some((cx, cy)) => (x + cx, y + cy),
+      None => return None
+    }
+  } else {
+    (x + width / 2.0, y + height / 2.0)
+  };
+  let mut circle = None;
+  let mut size = None;
+  let mut radii = Vec::new();
+  for word in words.slice_to(at).iter() {
+    match *word {
+      "circle" if circle.is_none() => circle = Some(true),
+      "ellipse" if circle.is_none() => circle = Some(false),
+      "closest-side" | "farthest-side" | "closest-corner" | "farthest-corner" if size.is_none() && radii.len() == 0 => size = Some(*word),
+      _ if size.is_none() && radii.len() < 2 => radii.push(*word),
+      _ => return None
+    }
+  }
+  let circle = match circle {
+    Some(circle) => circle,
+    None => radii.len() == 1
+  };
+  if radii.len() > 0 {
+    return match (circle, radii.len()) {
+      (true, 1) if !radii[0].ends_with("%") => length(radii[0], 0.0).map(|r| (cx, cy, r, r)),
+      (false, 2) => match (length(radii[0], width), length(radii[1], height)) {
+        (Some(rx), Some(ry)) => Some((cx, cy, rx, ry)),
+        _ => None
+      },
+      _ => None
+    };
+  }
+  let (left, right, top, bottom) = (cx - x, x + width - cx, cy - y, y + height - cy);
+  let (near_x, far_x) = (left.abs().min(right.abs()), left.abs().max(right.abs()));
+  let (near_y, far_y) = (top.abs().min(bottom.abs()), top.abs().max(bottom.abs()));
+  let (rx, ry) = match (circle, size.unwrap_or("farthest-corner")) {
+    (true, "closest-side") => (near_x.min(near_y), near_x.min(near_y)),
+    (true, "farthest-side") => (far_x.max(far_y), far_x.max(far_y)),
+    (true, "closest-corner") => (near_x.hypot(near_y), near_x.hypot(near_y)),
+    (true, _) => (far_x.hypot(far_y), far_x.hypot(far_y)),
+    // An ellipse keeps the aspect ratio of the matching side size and passes through the corner.
+    (false, "closest-side") => (near_x, near_y),
+    (false, "farthest-side") => (far_x, far_y),
+    (false, "closest-corner") => (near_x * std::f64::consts::SQRT2, near_y * std::f64::consts::SQRT2),
+    (false, _) => (far_x * std::f64::consts::SQRT2, far_y * std::f64::consts::SQRT2)
+  };
+  return Some((cx, cy, rx, ry));
+}
+
+/// Parses a CSS position of one or two keywords or lengths, relative to the top left corner of the box.
+fn position(words: &[&str], width: f64, height: f64) -> Option<(f64, f64)> {
+  let vertical = |word: &str| word == "top" || word == "bottom";
+  let horizontal = |word: &str| word == "left" || word == "right";
+  let (h, v) = match words.len() {
+    1 if vertical(words[0]) => ("center", words[0]),
+    1 => (words[0], "center"),
+    2 if vertical(words[0]) || horizontal(words[1]) => (words[1], words[0]),
+    2 => (words[0], words[1]),
+    _ => return None
+  };
+  let cx = match h {
+    "left" => Some(0.0),
+    "center" => Some(width / 2.0),
+    "right" => Some(width),
+    _ if vertical(h) => None,
+    _ => length(h, width)
+  };
+  let cy = match v {
+    "top" => Some(0.0),
+    "center" => Some(height / 2.0),
+    "bottom" => Some(height),
+    _ if horizontal(v) => None,
+    _ => length(v, height)
+  };
+  match (cx, cy) {
+    (Some(cx), Some(cy)) => Some((cx, cy)),
+    _ => None
+  }
+}
+
+/// Parses a CSS length in px, or a percentage of reference. A unitless zero is accepted.
+fn length(arg: &str, reference: f64) -> Option<f64> {
+  if arg.ends_with("%") {
+    return from_str::<f64>(arg.slice_to(arg.len() - 1)).map(|value| value * reference / 100.0);
+  }
+  if arg.ends_with("px") {
+    return from_str::<f64>(arg.slice_to(arg.len() - 2));
+  }
+  match from_str::<f64>(arg) {
+    Some(value) if value == 0.0 => Some(0.0),
+    _ => None
+  }
+}
+
+/// Parses a number, or a percentage, as a fraction of scale.
+fn fraction(arg: &str, scale: f64) -> Option<f64> {
+  if arg.ends_with("%") {
+    return from_str::<f64>(arg.slice_to(arg.len() - 1)).map(|value| value / 100.0);
+  }
+  return from_str::<f64>(arg).map(|value| value / scale);
+}
+
+/// A color stop whose position may still be missing.
+struct Stop {
+  offset: Option<f64>,
+  color: Color
+}
+
+/// Parses the color stops of a gradient, resolving missing and decreasing positions as described in CSS Images Module Level 3. Positions are fractions of the gradient ray, which is ray pixels long.
+fn color_stops(args: &[&str], ray: f64) -> Option<Vec<(f64, Color)>> {
+  let mut stops = Vec::new();
+  for arg in args.iter() {
+    let words = split_top_level(*arg, ' ');
+    if words.len() == 0 || words.len() > 3 {
+      return None;
+    }
+    let stop_color = match color(words[0]) {
+      Some(stop_color) => stop_color,
+      None => return None
+    };
+    if words.len() == 1 {
+      stops.push(Stop { offset: None, color: stop_color.clone() });
+    }
+    for word in words.slice_from(1).iter() {
+      let offset = if word.ends_with("%") {
+        fraction(*word, 1.0)
+      } else {
+        length(*word, 0.0).map(|value| if ray > 0.0 { value / ray } else { 0.0 })
+      };
+      match offset {
+        Some(offset) => stops.push(Stop { offset: Some(offset), color: stop_color.clone() }),
+        None => return None
+      }
+    }
+  }
+  if stops.len() < 2 {
+    return None;
+  }
+  let last = stops.len() - 1;
+  if stops.get(0).offset.is_none() {
+    stops.get_mut(0).offset = Some(0.0);
+  }
+  if stops.get(last).offset.is_none() {
+    stops.get_mut(last).offset = Some(1.0);
+  }
+  let mut max = std::f64::NEG_INFINITY;
+  for stop in stops.mut_iter() {
+    match stop.offset {
+      Some(offset) => {
+        max = max.max(offset);
+        stop.offset = Some(max);
+      },
+      None => {}
+    }
+  }
+  let mut resolved = Vec::with_capacity(stops.len());
+  let mut index = 0;
+  while index < stops.len() {
+    let start = index;
+    while stops.get(index).offset.is_none() {
+      index += 1;
+    }
+    // Stops without a position are spread evenly between the surrounding positioned stops.
+    let from = if start > 0 { stops.get(start - 1).offset.unwrap() } else { 0.0 };
+    let to = stops.get(index).offset.unwrap();
+    let count = (index - start + 1) as f64;
+    for n in range(start, index) {
+      let offset = from + (to - from) * (n - start + 1) as f64 / count;
+      resolved.push((offset, stops.get(n).color.clone()));
+    }
+    resolved.push((to, stops.get(index).color.clone()));
+    index += 1;
+  }
+  return Some(resolved);
+}
+
+/// Maps the offsets of stops onto [0 .. 1], so that offsets outside that range and repeating gradients keep their meaning. Returns the positions of the first and last stop along the gradient ray, the first one being at least lower, and the mapped stops.
+fn normalize(stops: Vec<(f64, Color)>, lower: f64) -> (f64, f64, Vec<(f64, Color)>) {
+  let &(first, _) = stops.get(0);
+  let &(last, _) = stops.get(stops.len() - 1);
+  let first = first.max(lower);
+  if last - first <= 1e-9 {
+    return (0.0, 1.0, stops);
+  }
+  let stops = stops.move_iter().map(|(offset, color)| ((offset - first) / (last - first), color)).collect();
+  return (first, last, stops);
+}
+
+/// Splits arg at the separator, ignoring separators nested in parentheses. A space separator matches any whitespace and empty pieces are skipped.
+fn split_top_level<'a>(arg: &'a str, separator: char) -> Vec<&'a str> {
+  let mut pieces = Vec::new();
+  let mut depth = 0;
+  let mut start = 0;
+  for (index, c) in arg.char_indices() {
+    match c {
+      '(' => depth += 1,
+      ')' => depth -= 1,
+      _ if depth == 0 && (c == separator || (separator == ' ' && c.is_whitespace())) => {
+        pieces.push(arg.slice(start, index).trim());
+        start = index + c.len_utf8_bytes();
+      },
+      _ => {}
+    }
+  }
+  pieces.push(arg.slice_from(start).trim());
+  if separator == ' ' {
+    pieces.retain(|piece| piece.len() > 0);
+  }
+  return pieces;
+}
+
+#[cfg(test)]
+mod test {
+  use std::f64::consts::{PI, SQRT2};
+  use super::{gradient, color, angle, direction, length, radial_shape, color_stops, split_top_level};
+  use super::super::super::color::Color;
+
+  fn offsets(stops: Option<Vec<(f64, Color)>>) -> Vec<f64> {
+    stops.unwrap().iter().map(|&(offset, _)| offset).collect()
+  }
+
+  #[test]
+  fn colors() {
+    assert_eq!(color("rgb(255, 0, 0)"), Some(Color::rgb(1.0, 0.0, 0.0)));
+    assert_eq!(color("rgba(255 128 0 / 50%)"), Some(Color::rgba(1.0, 128.0 / 255.0, 0.0, 0.5)));
+    assert_eq!(color(" Red "), Some(Color::rgb(1.0, 0.0, 0.0)));
+    assert_eq!(color("#ff8000"), Color::from_hex("ff8000"));
+    let green = color("hsl(120, 100%, 50%)").unwrap();
+    assert!(green.red.abs() < 1e-9 && (green.green - 1.0).abs() < 1e-9 && green.blue.abs() < 1e-9);
+    assert_eq!(color("rgb(1, 2)"), None);
+    assert_eq!(color("notacolor"), None);
+  }
+
+  #[test]
+  fn angles() {
+    assert!((angle("90deg").unwrap() - PI / 2.0).abs() < 1e-12);
+    assert!((angle("0.25turn").unwrap() - PI / 2.0).abs() < 1e-12);
+    assert!((angle("200grad").unwrap() - PI).abs() < 1e-12);
+    assert_eq!(angle("0"), Some(0.0));
+    assert_eq!(angle("5"), None);
+  }
+
+  #[test]
+  fn directions() {
+    assert_eq!(direction("to right", 100.0, 50.0), Some(PI / 2.0));
+    assert_eq!(direction("to bottom", 100.0, 50.0), Some(PI));
+    assert_eq!(direction("to top right", 100.0, 50.0), Some(50.0f64.atan2(100.0)));
+    assert_eq!(direction("to left right", 100.0, 50.0), None);
+  }
+
+  #[test]
+  fn lengths() {
+    assert_eq!(length("50%", 200.0), Some(100.0));
+    assert_eq!(length("12px", 0.0), Some(12.0));
+    assert_eq!(length("0", 0.0), Some(0.0));
+    assert_eq!(length("3", 0.0), None);
+  }
+
+  #[test]
+  fn radial_shapes() {
+    assert_eq!(radial_shape("", 0.0, 0.0, 100.0, 50.0), Some((50.0, 25.0, 50.0 * SQRT2, 25.0 * SQRT2)));
+    assert_eq!(radial_shape("circle farthest-side at 25% 50%", 0.0, 0.0, 100.0, 50.0), Some((25.0, 25.0, 75.0, 75.0)));
+    assert_eq!(radial_shape("ellipse 10px 20px at center", 10.0, 10.0, 100.0, 50.0), Some((60.0, 35.0, 10.0, 20.0)));
+    assert_eq!(radial_shape("circle 50%", 0.0, 0.0, 100.0, 50.0), None);
+  }
+
+  #[test]
+  fn stop_positions() {
+    assert_eq!(offsets(color_stops(&["red", "lime", "blue"], 100.0)), vec![0.0, 0.5, 1.0]);
+    assert_eq!(offsets(color_stops(&["red 50%", "blue 20%"], 100.0)), vec![0.5, 0.5]);
+    assert_eq!(offsets(color_stops(&["red 0%", "lime", "blue 20px"], 100.0)), vec![0.0, 0.1, 0.2]);
+    assert_eq!(offsets(color_stops(&["red 10% 30%", "blue"], 100.0)), vec![0.1, 0.3, 1.0]);
+    assert!(color_stops(&["red"], 100.0).is_none());
+  }
+
+  #[test]
+  fn top_level_split() {
+    assert_eq!(split_top_level("rgb(1, 2, 3) 10%, blue", ','), vec!["rgb(1, 2, 3) 10%", "blue"]);
+    assert_eq!(split_top_level("  red   rgb(1 2 3) ", ' '), vec!["red", "rgb(1 2 3)"]);
+  }
+
+  #[test]
+  fn unsupported_gradients() {
+    assert!(gradient("linear-gradient(red)", 0.0, 0.0, 10.0, 10.0).is_none());
+    assert!(gradient("conic-gradient(red, blue)", 0.0, 0.0, 10.0, 10.0).is_none());
+    assert!(gradient("linear-gradient(red, blue", 0.0, 0.0, 10.0, 10.0).is_none());
+  }
+}
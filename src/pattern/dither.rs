@@ -0,0 +1,20 @@
+//! Describe dithering mode
+
+/// pattern::dither::Dither is an intermediate between Filter and Antialias, describing the dithering applied to gradients and other patterns when they are rendered to a surface of lower color depth.
+///
+/// Since 1.18
+#[repr(i32)]
+#[deriving(Clone, PartialEq, Show)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
+pub enum Dither {
+  /// No dithering, since 1.18
+  None = 0,
+  /// Default choice at cairo compile time. Currently NONE, since 1.18
+  Default = 1,
+  /// Fastest dithering algorithm supported by the backend, since 1.18
+  Fast = 2,
+  /// An algorithm with smoother dithering than FAST, since 1.18
+  Good = 3,
+  /// Best algorithm available in the backend, since 1.18
+  Best = 4
+}
@@ -1,5 +1,8 @@
 //! Describe color/alpha for areas "outside" pattern's natural area
 
+use std;
+use std::ascii::StrAsciiExt;
+
 /// pattern::extend::Extend is used to describe how pattern color/alpha will be determined for areas "outside" the pattern's natural area, (for example, outside the surface bounds or outside the gradient geometry).
 /// 
 /// Mesh patterns are not affected by the extend mode.
@@ -10,6 +13,7 @@
 /// 
 /// Since 1.0
 #[repr(i32)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub enum Extend {
   /// pixels outside of the source pattern are fully transparent (Since 1.0)
   None = 0,
@@ -21,3 +25,28 @@ pub enum Extend {
   Pad = 3,
 }
 
+impl std::from_str::FromStr for Extend {
+  /// Parses pattern::extend::Extend, using the lower case cairo names. Case and surrounding whitespace are ignored.
+  fn from_str(s: &str) -> Option<Extend> {
+    match s.trim().to_ascii_lower().as_slice() {
+      "none" => Some(None),
+      "repeat" => Some(Repeat),
+      "reflect" => Some(Reflect),
+      "pad" => Some(Pad),
+      _ => std::option::None
+    }
+  }
+}
+
+impl std::fmt::Show for Extend {
+  /// Formats pattern::extend::Extend with the name accepted by from_str().
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let name = match *self {
+      None => "none",
+      Repeat => "repeat",
+      Reflect => "reflect",
+      Pad => "pad"
+    };
+    f.pad(name)
+  }
+}
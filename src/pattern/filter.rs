@@ -1,9 +1,13 @@
 //! Describe filter type
 
+use std;
+use std::ascii::StrAsciiExt;
+
 /// pattern::filter::Filter is used to indicate what filtering should be applied when reading pixel values from patterns. See cairo_pattern_set_filter() for indicating the desired filter to be used with a particular pattern.
 /// 
 /// Since 1.0
 #[repr(i32)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub enum Filter {
   /// A high-performance filter, with quality similar to CAIRO_FILTER_NEAREST (Since 1.0)
   Fast = 0,
@@ -18,3 +22,33 @@ pub enum Filter {
   /// This filter value is currently unimplemented, and should not be used in current code. (Since 1.0)
   Gaussian = 5
 }
+
+impl std::from_str::FromStr for Filter {
+  /// Parses pattern::filter::Filter, using the lower case cairo names. Case and surrounding whitespace are ignored.
+  fn from_str(s: &str) -> Option<Filter> {
+    match s.trim().to_ascii_lower().as_slice() {
+      "fast" => Some(Fast),
+      "good" => Some(Good),
+      "best" => Some(Best),
+      "nearest" => Some(Nearest),
+      "bilinear" => Some(Bilinear),
+      "gaussian" => Some(Gaussian),
+      _ => None
+    }
+  }
+}
+
+impl std::fmt::Show for Filter {
+  /// Formats pattern::filter::Filter with the name accepted by from_str().
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let name = match *self {
+      Fast => "fast",
+      Good => "good",
+      Best => "best",
+      Nearest => "nearest",
+      Bilinear => "bilinear",
+      Gaussian => "gaussian"
+    };
+    f.pad(name)
+  }
+}
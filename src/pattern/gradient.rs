@@ -0,0 +1,182 @@
+//! Build gradient patterns from lists of color stops
+
+use super::Pattern;
+use super::pattern_type;
+use super::super::Status;
+use super::super::color::Color;
+
+/// pattern::gradient::Geometry describes the control vector of a gradient pattern, as passed to cairo_pattern_create_linear() or cairo_pattern_create_radial().
+#[deriving(Clone, PartialEq, Show)]
+pub enum Geometry {
+  /// a linear gradient along the line from (x0, y0) to (x1, y1)
+  Linear(f64, f64, f64, f64),
+  /// a radial gradient between the circles (cx0, cy0, radius0) and (cx1, cy1, radius1)
+  Radial(f64, f64, f64, f64, f64, f64)
+}
+
+/// A pattern::gradient::GradientBuilder collects the geometry and color stops of a gradient, so the gradient can be stored, compared and turned into a pattern::Pattern later.
+///
+/// Stops are kept in the order they are added and are passed to cairo_pattern_add_color_stop_rgba() in that order, so stops with identical offsets keep their relative order as described for cairo_pattern_add_color_stop_rgb().
+#[deriving(Clone, PartialEq, Show)]
+pub struct GradientBuilder {
+  /// the control vector of the gradient
+  pub geometry: Geometry,
+  /// the color stops as (offset, color) pairs
+  pub stops: Vec<(f64, Color)>
+}
+
+impl GradientBuilder {
+  /// Creates a builder for a gradient with the given geometry and color stops.
+  ///
+  /// geometry : the control vector of the gradient
+  ///
+  /// stops : the color stops as (offset, color) pairs, offsets in the range [0.0 .. 1.0]
+  pub fn new(geometry: Geometry, stops: Vec<(f64, Color)>) -> GradientBuilder {
+    GradientBuilder { geometry: geometry, stops: stops }
+  }
+
+  /// Creates a builder for a linear gradient along the line defined by (x0, y0) and (x1, y1), without any color stops. See cairo_pattern_create_linear().
+  ///
+  /// x0 : x coordinate of the start point
+  ///
+  /// y0 : y coordinate of the start point
+  ///
+  /// x1 : x coordinate of the end point
+  ///
+  /// y1 : y coordinate of the end point
+  pub fn linear(x0: f64, y0: f64, x1: f64, y1: f64) -> GradientBuilder {
+    GradientBuilder::new(Linear(x0, y0, x1, y1), Vec::new())
+  }
+
+  /// Creates a builder for a radial gradient between the two circles defined by (cx0, cy0, radius0) and (cx1, cy1, radius1), without any color stops. See cairo_pattern_create_radial().
+  ///
+  /// cx0 : x coordinate for the center of the start circle
+  ///
+  /// cy0 : y coordinate for the center of the start circle
+  ///
+  /// radius0 : radius of the start circle
+  ///
+  /// cx1 : x coordinate for the center of the end circle
+  ///
+  /// cy1 : y coordinate for the center of the end circle
+  ///
+  /// radius1 : radius of the end circle
+  pub fn radial(cx0: f64, cy0: f64, radius0: f64, cx1: f64, cy1: f64, radius1: f64) -> GradientBuilder {
+    GradientBuilder::new(Radial(cx0, cy0, radius0, cx1, cy1, radius1), Vec::new())
+  }
+
+  /// Reads the geometry and all color stops back from a linear or radial gradient pattern.
+  ///
+  /// pattern : a gradient pattern::Pattern
+  ///
+  /// Returns : the builder describing pattern, or CAIRO_STATUS_PATTERN_TYPE_MISMATCH if pattern is not a gradient pattern. Errors reported by cairo while reading the pattern are returned as they are.
+  pub fn from_pattern(pattern: &mut Pattern) -> Result<GradientBuilder, Status> {
+    let geometry = match pattern.get_type() {
+      pattern_type::Linear => {
+        let (status, x0, y0, x1, y1) = pattern.get_linear_points();
+        match status {
+          super::super::Success => Linear(x0, y0, x1, y1),
+          error => return Err(error)
+        }
+      },
+      pattern_type::Radial => {
+        let (status, cx0, cy0, radius0, cx1, cy1, radius1) = pattern.get_radial_circles();
+        match status {
+          super::super::Success => Radial(cx0, cy0, radius0, cx1, cy1, radius1),
+          error => return Err(error)
+        }
+      },
+      _ => return Err(super::super::PatternTypeMismatch)
+    };
+    let (status, count) = pattern.get_color_stop_count();
+    match status {
+      super::super::Success => {},
+      error => return Err(error)
+    }
+    let mut stops = Vec::with_capacity(count as uint);
+    for index in range(0, count) {
+      let (status, offset, red, green, blue, alpha) = pattern.get_color_stop_rgba(index);
+      match status {
+        super::super::Success => stops.push((offset, Color::rgba(red, green, blue, alpha))),
+        error => return Err(error)
+      }
+    }
+    return Ok(GradientBuilder::new(geometry, stops));
+  }
+
+  /// Adds a color stop and returns the builder.
+  ///
+  /// offset : an offset in the range [0.0 .. 1.0]
+  ///
+  /// color : the color of the stop
+  pub fn stop(mut self, offset: f64, color: Color) -> GradientBuilder {
+    self.stops.push((offset, color));
+    return self;
+  }
+
+  /// Appends a list of color stops and returns the builder.
+  ///
+  /// stops : the color stops as (offset, color) pairs, offsets in the range [0.0 .. 1.0]
+  pub fn add_stops(mut self, stops: Vec<(f64, Color)>) -> GradientBuilder {
+    self.stops.push_all_move(stops);
+    return self;
+  }
+
+  /// Creates a new gradient pattern::Pattern with the geometry and color stops of the builder.
+  ///
+  /// Returns : the newly created pattern::Pattern. This function will always return a valid pattern, but if an error occurred the pattern status will be set to an error. To inspect the status of a pattern use cairo_pattern_status().
+  pub fn build(&self) -> Pattern {
+    let mut pattern = match self.geometry {
+      Linear(x0, y0, x1, y1) => Pattern::linear(x0, y0, x1, y1),
+      Radial(cx0, cy0, radius0, cx1, cy1, radius1) => Pattern::radial(cx0, cy0, radius0, cx1, cy1, radius1)
+    };
+    for &(offset, ref color) in self.stops.iter() {
+      pattern.add_color_stop(offset, color.clone());
+    }
+    return pattern;
+  }
+}
+
+/// An iterator over the color stops of a gradient pattern, returned by pattern::Pattern::color_stops().
+///
+/// Each item is the (offset, color) pair reported by cairo_pattern_get_color_stop_rgba(). The iteration ends early if cairo reports an error.
+pub struct ColorStops<'a> {
+  pattern: &'a mut Pattern,
+  index: i32,
+  count: i32
+}
+
+impl<'a> ColorStops<'a> {
+  /// Creates an iterator over the color stops of pattern. If pattern is not a gradient pattern the iterator is empty.
+  ///
+  /// pattern : a pattern::Pattern
+  pub fn new(pattern: &'a mut Pattern) -> ColorStops<'a> {
+    let count = match pattern.get_color_stop_count() {
+      (super::super::Success, count) => count,
+      _ => 0
+    };
+    ColorStops { pattern: pattern, index: 0, count: count }
+  }
+}
+
+impl<'a> Iterator<(f64, Color)> for ColorStops<'a> {
+  fn next(&mut self) -> Option<(f64, Color)> {
+    if self.index >= self.count {
+      return None;
+    }
+    let (status, offset, red, green, blue, alpha) = self.pattern.get_color_stop_rgba(self.index);
+    self.index += 1;
+    match status {
+      super::super::Success => Some((offset, Color::rgba(red, green, blue, alpha))),
+      _ => {
+        self.index = self.count;
+        None
+      }
+    }
+  }
+
+  fn size_hint(&self) -> (uint, Option<uint>) {
+    let remaining = (self.count - self.index) as uint;
+    (0, Some(remaining))
+  }
+}
@@ -0,0 +1,285 @@
+//! Build mesh patterns from typed patches
+
+use super::Pattern;
+use super::pattern_type;
+use super::super::Status;
+use super::super::color::Color;
+use super::super::path;
+
+/// pattern::mesh::Side describes one side of a mesh patch. Each side starts at the end of the previous side, the first side starts at the first point of the patch.
+#[deriving(Clone, PartialEq, Show)]
+pub enum Side {
+  /// a straight side ending at (x, y), see cairo_mesh_pattern_line_to()
+  Line(f64, f64),
+  /// a cubic Bézier side ending at (x3, y3), using (x1, y1) and (x2, y2) as control points, see cairo_mesh_pattern_curve_to()
+  Curve(f64, f64, f64, f64, f64, f64)
+}
+
+/// A pattern::mesh::Patch describes one tensor-product patch of a mesh pattern: its first point, its sides, the optional internal control points P0 .. P3 and the optional colors of the corners C0 .. C3.
+///
+/// A patch with less than 4 sides is closed with a straight line back to its first point, so a triangle is described as a patch with 3 straight sides. Control points which are not set are computed as for a Coons patch and corner colors which are not set are transparent black, as described for cairo_pattern_create_mesh().
+#[deriving(Clone, PartialEq, Show)]
+pub struct Patch {
+  /// the first point of the patch, which is corner C0
+  pub start: (f64, f64),
+  /// the sides of the patch, at most 4
+  pub sides: Vec<Side>,
+  /// the internal control points P0 .. P3
+  pub control_points: [Option<(f64, f64)>, ..4],
+  /// the colors of the corners C0 .. C3
+  pub corner_colors: [Option<Color>, ..4],
+  // the first out-of-range control point or corner passed to control_point() or corner_color()
+  invalid_index: Option<uint>
+}
+
+impl Patch {
+  /// Creates a patch starting at (x, y) without any sides, control points or corner colors.
+  ///
+  /// x : the X coordinate of corner C0
+  ///
+  /// y : the Y coordinate of corner C0
+  pub fn new(x: f64, y: f64) -> Patch {
+    Patch {
+      start: (x, y),
+      sides: Vec::new(),
+      control_points: [None, None, None, None],
+      corner_colors: [None, None, None, None],
+      invalid_index: None
+    }
+  }
+
+  /// Creates a Coons patch from its first point, its 4 sides and the colors of its 4 corners. The internal control points are computed by cairo.
+  ///
+  /// x : the X coordinate of corner C0
+  ///
+  /// y : the Y coordinate of corner C0
+  ///
+  /// sides : the sides from C0 to C1, C1 to C2, C2 to C3 and C3 to C0
+  ///
+  /// colors : the colors of the corners C0 .. C3
+  pub fn coons(x: f64, y: f64, sides: [Side, ..4], colors: [Color, ..4]) -> Patch {
+    let mut patch = Patch::new(x, y);
+    patch.sides.push_all(sides.as_slice());
+    for (corner, color) in colors.iter().enumerate() {
+      patch.corner_colors[corner] = Some(color.clone());
+    }
+    return patch;
+  }
+
+  /// Creates a Gouraud-shaded triangle as a degenerate patch. The fourth corner coincides with the first one and gets the same color.
+  ///
+  /// p0 : the first vertex, corner C0
+  ///
+  /// p1 : the second vertex, corner C1
+  ///
+  /// p2 : the third vertex, corner C2
+  ///
+  /// c0, c1, c2 : the colors of the vertices
+  pub fn triangle(p0: (f64, f64), p1: (f64, f64), p2: (f64, f64), c0: Color, c1: Color, c2: Color) -> Patch {
+    let (x0, y0) = p0;
+    let (x1, y1) = p1;
+    let (x2, y2) = p2;
+    Patch::new(x0, y0)
+      .line_to(x1, y1)
+      .line_to(x2, y2)
+      .corner_color(0, c0)
+      .corner_color(1, c1)
+      .corner_color(2, c2)
+  }
+
+  /// Adds a straight side ending at (x, y) and returns the patch.
+  ///
+  /// x : the X coordinate of the end of the new side
+  ///
+  /// y : the Y coordinate of the end of the new side
+  pub fn line_to(mut self, x: f64, y: f64) -> Patch {
+    self.sides.push(Line(x, y));
+    return self;
+  }
+
+  /// Adds a cubic Bézier side ending at (x3, y3) and returns the patch.
+  ///
+  /// x1, y1 : the first control point
+  ///
+  /// x2, y2 : the second control point
+  ///
+  /// x3, y3 : the end of the new side
+  pub fn curve_to(mut self, x1: f64, y1: f64, x2: f64, y2: f64, x3: f64, y3: f64) -> Patch {
+    self.sides.push(Curve(x1, y1, x2, y2, x3, y3));
+    return self;
+  }
+
+  /// Sets the internal control point point_num and returns the patch. Valid values for point_num are from 0 to 3; other values are reported by pattern::mesh::Patch::validate().
+  ///
+  /// point_num : the control point to set the position for
+  ///
+  /// x : the X coordinate of the control point
+  ///
+  /// y : the Y coordinate of the control point
+  pub fn control_point(mut self, point_num: uint, x: f64, y: f64) -> Patch {
+    if point_num < 4 {
+      self.control_points[point_num] = Some((x, y));
+    } else if self.invalid_index.is_none() {
+      self.invalid_index = Some(point_num);
+    }
+    return self;
+  }
+
+  /// Sets the color of the corner corner_num and returns the patch. Valid values for corner_num are from 0 to 3; other values are reported by pattern::mesh::Patch::validate().
+  ///
+  /// corner_num : the corner to set the color for
+  ///
+  /// color : the color of the corner
+  pub fn corner_color(mut self, corner_num: uint, color: Color) -> Patch {
+    if corner_num < 4 {
+      self.corner_colors[corner_num] = Some(color);
+    } else if self.invalid_index.is_none() {
+      self.invalid_index = Some(corner_num);
+    }
+    return self;
+  }
+
+  /// Checks that the patch can be added to a mesh pattern.
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS, or CAIRO_STATUS_INVALID_MESH_CONSTRUCTION if the patch has more than 4 sides, or CAIRO_STATUS_INVALID_INDEX if a control point or corner outside 0 .. 3 was set.
+  pub fn validate(&self) -> Status {
+    if self.sides.len() > 4 {
+      return super::super::InvalidMeshConstruction;
+    }
+    if self.invalid_index.is_some() {
+      return super::super::InvalidIndex;
+    }
+    return super::super::Success;
+  }
+
+  /// Adds the patch to the mesh pattern, following the call sequence required by cairo_mesh_pattern_begin_patch().
+  ///
+  /// pattern : a mesh pattern::Pattern
+  pub fn add_to(&self, pattern: &mut Pattern) {
+    let (x, y) = self.start;
+    pattern.begin_patch();
+    pattern.move_to(x, y);
+    for side in self.sides.iter() {
+      match *side {
+        Line(x, y) => pattern.line_to(x, y),
+        Curve(x1, y1, x2, y2, x3, y3) => pattern.curve_to(x1, y1, x2, y2, x3, y3)
+      }
+    }
+    for (point_num, point) in self.control_points.iter().enumerate() {
+      match *point {
+        Some((x, y)) => pattern.set_control_point(point_num as i32, x, y),
+        None => {}
+      }
+    }
+    for (corner_num, color) in self.corner_colors.iter().enumerate() {
+      match *color {
+        Some(ref color) => pattern.set_corner_color(corner_num as i32, color.clone()),
+        None => {}
+      }
+    }
+    pattern.end_patch();
+  }
+}
+
+/// A pattern::mesh::MeshBuilder collects the patches of a mesh pattern, so they can be checked before any of them is passed to cairo.
+#[deriving(Clone, PartialEq, Show)]
+pub struct MeshBuilder {
+  /// the patches of the mesh, in painting order
+  pub patches: Vec<Patch>
+}
+
+impl MeshBuilder {
+  /// Creates a builder for a mesh pattern without any patches.
+  pub fn new() -> MeshBuilder {
+    MeshBuilder { patches: Vec::new() }
+  }
+
+  /// Adds a patch and returns the builder.
+  ///
+  /// patch : the patch to add
+  pub fn patch(mut self, patch: Patch) -> MeshBuilder {
+    self.patches.push(patch);
+    return self;
+  }
+
+  /// Reads all patches back from a mesh pattern. Cairo reports every side as a curve and computes the control points and corner colors which were not set, so the patches read back are fully specified.
+  ///
+  /// pattern : a mesh pattern::Pattern
+  ///
+  /// Returns : the builder describing pattern, or CAIRO_STATUS_PATTERN_TYPE_MISMATCH if pattern is not a mesh pattern. Errors reported by cairo while reading the pattern are returned as they are.
+  pub fn from_pattern(pattern: &mut Pattern) -> Result<MeshBuilder, Status> {
+    match pattern.get_type() {
+      pattern_type::Mesh => {},
+      _ => return Err(super::super::PatternTypeMismatch)
+    }
+    let (status, count) = pattern.get_patch_count();
+    match status {
+      super::super::Success => {},
+      error => return Err(error)
+    }
+    let mut patches = Vec::with_capacity(count as uint);
+    for patch_num in range(0, count) {
+      let outline = pattern.get_path(patch_num);
+      match outline.status() {
+        super::super::Success => {},
+        error => return Err(error)
+      }
+      let mut patch = Patch::new(0.0, 0.0);
+      for segment in outline.segments().iter() {
+        match *segment {
+          path::MoveTo(x, y) => patch.start = (x, y),
+          path::LineTo(x, y) => patch.sides.push(Line(x, y)),
+          path::CurveTo(x1, y1, x2, y2, x3, y3) => patch.sides.push(Curve(x1, y1, x2, y2, x3, y3)),
+          path::ClosePath => {}
+        }
+      }
+      for point_num in range(0u, 4) {
+        let (status, x, y) = pattern.get_control_point(patch_num, point_num as i32);
+        match status {
+          super::super::Success => patch.control_points[point_num] = Some((x, y)),
+          error => return Err(error)
+        }
+      }
+      for corner_num in range(0u, 4) {
+        let (status, red, green, blue, alpha) = pattern.get_corner_color_rgba(patch_num, corner_num as i32);
+        match status {
+          super::super::Success => patch.corner_colors[corner_num] = Some(Color::rgba(red, green, blue, alpha)),
+          error => return Err(error)
+        }
+      }
+      patches.push(patch);
+    }
+    return Ok(MeshBuilder { patches: patches });
+  }
+
+  /// Checks every patch of the builder, see pattern::mesh::Patch::validate().
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS, or the status of the first patch which can not be added to a mesh pattern.
+  pub fn validate(&self) -> Status {
+    for patch in self.patches.iter() {
+      match patch.validate() {
+        super::super::Success => {},
+        error => return error
+      }
+    }
+    return super::super::Success;
+  }
+
+  /// Creates a new mesh pattern::Pattern with the patches of the builder. The patches are validated before the pattern is created.
+  ///
+  /// Returns : the newly created pattern::Pattern, or the status of the first invalid patch, or the error status of the pattern if cairo failed to construct it.
+  pub fn build(&self) -> Result<Pattern, Status> {
+    match self.validate() {
+      super::super::Success => {},
+      error => return Err(error)
+    }
+    let mut pattern = Pattern::mesh();
+    for patch in self.patches.iter() {
+      patch.add_to(&mut pattern);
+    }
+    match pattern.status() {
+      super::super::Success => Ok(pattern),
+      error => Err(error)
+    }
+  }
+}
@@ -2,6 +2,9 @@
 
 use std;
 use libc;
+use ffi;
+use std::sync::atomics::{AtomicUint, SeqCst};
+use super::color::Color;
 
 /// A pattern::Pattern represents a source when drawing onto a surface. There are different subtypes of pattern::Pattern, for different types of sources; for example, cairo_pattern_create_rgb() creates a pattern for a solid opaque color.
 ///
@@ -39,7 +42,7 @@ impl Pattern {
   /// Since 1.0
   pub fn add_color_stop_rgb(&mut self, offset: f64, red: f64, green: f64, blue: f64) {
     unsafe {
-      cairo_pattern_add_color_stop_rgb(self.opaque, offset, red, green, blue);
+      ffi::cairo_pattern_add_color_stop_rgb(self.opaque, offset, red, green, blue);
     }
   }
 
@@ -66,10 +69,21 @@ impl Pattern {
   /// Since 1.0
   pub fn add_color_stop_rgba(&mut self, offset: f64, red: f64, green: f64, blue: f64, alpha: f64) {
     unsafe {
-      cairo_pattern_add_color_stop_rgba(self.opaque, offset, red, green, blue, alpha);
+      ffi::cairo_pattern_add_color_stop_rgba(self.opaque, offset, red, green, blue, alpha);
     }
   }
 
+  /// Adds a color stop to a gradient pattern, see cairo_pattern_add_color_stop_rgba().
+  ///
+  /// pattern : a pattern::Pattern
+  ///
+  /// offset : an offset in the range [0.0 .. 1.0]
+  ///
+  /// color : the color of the stop
+  pub fn add_color_stop(&mut self, offset: f64, color: Color) {
+    self.add_color_stop_rgba(offset, color.red, color.green, color.blue, color.alpha);
+  }
+
   /// Gets the number of color stops specified in the given gradient pattern.
   ///
   /// pattern : a pattern::Pattern
@@ -82,7 +96,7 @@ impl Pattern {
   pub fn get_color_stop_count(&mut self) -> (super::Status, i32) {
     unsafe {
       let mut stop_count:i32 = std::intrinsics::init();
-      let foreign_result = cairo_pattern_get_color_stop_count(self.opaque, &mut stop_count);
+      let foreign_result = std::mem::transmute(ffi::cairo_pattern_get_color_stop_count(self.opaque, &mut stop_count));
       return (foreign_result, stop_count);
     }
   }
@@ -113,11 +127,20 @@ impl Pattern {
       let mut green:f64 = std::intrinsics::init();
       let mut blue:f64 = std::intrinsics::init();
       let mut alpha:f64 = std::intrinsics::init();
-      let foreign_result = cairo_pattern_get_color_stop_rgba(self.opaque, stop_count, &mut offset, &mut red, &mut green, &mut blue, &mut alpha);
+      let foreign_result = std::mem::transmute(ffi::cairo_pattern_get_color_stop_rgba(self.opaque, stop_count, &mut offset, &mut red, &mut green, &mut blue, &mut alpha));
       return (foreign_result, offset, red, green, blue, alpha);
     }
   }
 
+  /// Returns an iterator over all color stops of a gradient pattern as (offset, color) pairs, in the same order as cairo_pattern_get_color_stop_rgba() reports them. Together with pattern::gradient::GradientBuilder this allows a gradient to be stored and reconstructed exactly.
+  ///
+  /// If the pattern is not a gradient pattern the iterator is empty.
+  ///
+  /// pattern : a pattern::Pattern
+  pub fn color_stops<'a>(&'a mut self) -> gradient::ColorStops<'a> {
+    gradient::ColorStops::new(self)
+  }
+
   /// Creates a new pattern::Pattern corresponding to an opaque color. The color components are floating point numbers in the range 0 to 1. If the values passed in are outside that range, they will be clamped.
   /// 
   /// red : red component of the color
@@ -132,7 +155,7 @@ impl Pattern {
   /// Since 1.0
   pub fn rgb(red: f64, green: f64, blue: f64) -> Pattern {
     unsafe {
-      let foreign_result = cairo_pattern_create_rgb(red, green, blue);
+      let foreign_result = Pattern { opaque: ffi::cairo_pattern_create_rgb(red, green, blue) };
       return foreign_result;
     }
   }
@@ -152,11 +175,32 @@ impl Pattern {
   /// Since 1.0
   pub fn rgba(red: f64, green: f64, blue: f64, alpha: f64) -> Pattern {
     unsafe {
-      let foreign_result = cairo_pattern_create_rgba(red, green, blue, alpha);
+      let foreign_result = Pattern { opaque: ffi::cairo_pattern_create_rgba(red, green, blue, alpha) };
       return foreign_result;
     }
   }
 
+  /// Creates a new pattern::Pattern corresponding to a color, see cairo_pattern_create_rgba().
+  ///
+  /// color : the color of the pattern
+  ///
+  /// Returns : the newly created pattern::Pattern if successful, or an error pattern in case of no memory.
+  pub fn color(color: Color) -> Pattern {
+    Pattern::rgba(color.red, color.green, color.blue, color.alpha)
+  }
+
+  /// Creates a new gradient pattern::Pattern from a CSS gradient function, laid out for a box, see pattern::css::gradient().
+  ///
+  /// css : the gradient function, for example "linear-gradient(45deg, red 0%, blue 100%)"
+  ///
+  /// bbox : the box as (x, y, width, height)
+  ///
+  /// Returns : the newly created pattern::Pattern, or None if css is not a supported gradient function.
+  pub fn from_css_gradient(css: &str, bbox: (f64, f64, f64, f64)) -> Option<Pattern> {
+    let (x, y, width, height) = bbox;
+    css::gradient(css, x, y, width, height)
+  }
+
   /// Gets the solid color for a solid color pattern.
   /// 
   /// pattern : a pattern::Pattern
@@ -178,7 +222,7 @@ impl Pattern {
       let mut green:f64 = std::intrinsics::init();
       let mut blue:f64 = std::intrinsics::init();
       let mut alpha:f64 = std::intrinsics::init();
-      let foreign_result = cairo_pattern_get_rgba(self.opaque, &mut red, &mut green, &mut blue, &mut alpha);
+      let foreign_result = std::mem::transmute(ffi::cairo_pattern_get_rgba(self.opaque, &mut red, &mut green, &mut blue, &mut alpha));
       return (foreign_result, red, green, blue, alpha);
     }
   }
@@ -200,7 +244,7 @@ impl Pattern {
   /// Since 1.4
   pub fn for_surface(surface: super::surface::Surface) -> Pattern {
     unsafe {
-      let foreign_result = cairo_pattern_create_for_surface(surface);
+      let foreign_result = Pattern { opaque: ffi::cairo_pattern_create_for_surface(surface.opaque) };
       return foreign_result;
     }
   }
@@ -216,9 +260,9 @@ impl Pattern {
   /// Since 1.4
   pub fn get_surface(&mut self) -> (super::Status, super::surface::Surface) {
     unsafe {
-      let mut surface:super::surface::Surface = std::intrinsics::init();
-      let foreign_result = cairo_pattern_get_surface(self.opaque, &mut surface);
-      return (foreign_result, surface);
+      let mut surface:*mut ffi::cairo_surface_t = std::ptr::mut_null();
+      let foreign_result = std::mem::transmute(ffi::cairo_pattern_get_surface(self.opaque, &mut surface));
+      return (foreign_result, super::surface::Surface { opaque: ffi::cairo_surface_reference(surface) });
     }
   }
 
@@ -239,7 +283,7 @@ impl Pattern {
   /// Since 1.0
   pub fn linear(x0: f64, y0: f64, x1: f64, y1: f64) -> Pattern {
     unsafe {
-      let foreign_result = cairo_pattern_create_linear(x0, y0, x1, y1);
+      let foreign_result = Pattern { opaque: ffi::cairo_pattern_create_linear(x0, y0, x1, y1) };
       return foreign_result;
     }
   }
@@ -265,7 +309,7 @@ impl Pattern {
       let mut y0:f64 = std::intrinsics::init();
       let mut x1:f64 = std::intrinsics::init();
       let mut y1:f64 = std::intrinsics::init();
-      let foreign_result = cairo_pattern_get_linear_points(self.opaque, &mut x0, &mut y0, &mut x1, &mut y1);
+      let foreign_result = std::mem::transmute(ffi::cairo_pattern_get_linear_points(self.opaque, &mut x0, &mut y0, &mut x1, &mut y1));
       return (foreign_result, x0, y0, x1, y1);
     }
   }
@@ -291,7 +335,7 @@ impl Pattern {
   /// Since 1.0
   pub fn radial(cx0: f64, cy0: f64, radius0: f64, cx1: f64, cy1: f64, radius1: f64) -> Pattern {
     unsafe {
-      let foreign_result = cairo_pattern_create_radial(cx0, cy0, radius0, cx1, cy1, radius1);
+      let foreign_result = Pattern { opaque: ffi::cairo_pattern_create_radial(cx0, cy0, radius0, cx1, cy1, radius1) };
       return foreign_result;
     }
   }
@@ -323,7 +367,7 @@ impl Pattern {
       let mut x1:f64 = std::intrinsics::init();
       let mut y1:f64 = std::intrinsics::init();
       let mut r1:f64 = std::intrinsics::init();
-      let foreign_result = cairo_pattern_get_radial_circles(self.opaque, &mut x0, &mut y0, &mut r0, &mut x1, &mut y1, &mut r1);
+      let foreign_result = std::mem::transmute(ffi::cairo_pattern_get_radial_circles(self.opaque, &mut x0, &mut y0, &mut r0, &mut x1, &mut y1, &mut r1));
       return (foreign_result, x0, y0, r0, x1, y1, r1);
     }
   }
@@ -409,7 +453,7 @@ impl Pattern {
   /// Since 1.12
   pub fn mesh() -> Pattern {
     unsafe {
-      let foreign_result = cairo_pattern_create_mesh();
+      let foreign_result = Pattern { opaque: ffi::cairo_pattern_create_mesh() };
       return foreign_result;
     }
   }
@@ -427,7 +471,7 @@ impl Pattern {
   /// Since 1.12
   pub fn begin_patch(&mut self) {
     unsafe {
-      cairo_mesh_pattern_begin_patch(self.opaque);
+      ffi::cairo_mesh_pattern_begin_patch(self.opaque);
     }
   }
 
@@ -442,7 +486,7 @@ impl Pattern {
   /// Since 1.12
   pub fn end_patch(&mut self) {
     unsafe {
-      cairo_mesh_pattern_end_patch(self.opaque);
+      ffi::cairo_mesh_pattern_end_patch(self.opaque);
     }
   }
 
@@ -461,7 +505,7 @@ impl Pattern {
   /// Since 1.12
   pub fn move_to(&mut self, x: f64, y: f64) {
     unsafe {
-      cairo_mesh_pattern_move_to(self.opaque, x, y);
+      ffi::cairo_mesh_pattern_move_to(self.opaque, x, y);
     }
   }
 
@@ -482,7 +526,7 @@ impl Pattern {
   /// Since 1.12
   pub fn line_to(&mut self, x: f64, y: f64) {
     unsafe {
-      cairo_mesh_pattern_line_to(self.opaque, x, y);
+      ffi::cairo_mesh_pattern_line_to(self.opaque, x, y);
     }
   }
 
@@ -511,7 +555,7 @@ impl Pattern {
   /// Since 1.12
   pub fn curve_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, x3: f64, y3: f64) {
     unsafe {
-      cairo_mesh_pattern_curve_to(self.opaque, x1, y1, x2, y2, x3, y3);
+      ffi::cairo_mesh_pattern_curve_to(self.opaque, x1, y1, x2, y2, x3, y3);
     }
   }
 
@@ -532,7 +576,7 @@ impl Pattern {
   /// Since 1.12
   pub fn set_control_point(&mut self, point_num: i32, x: f64, y: f64) {
     unsafe {
-      cairo_mesh_pattern_set_control_point(self.opaque, point_num, x, y);
+      ffi::cairo_mesh_pattern_set_control_point(self.opaque, point_num as libc::c_uint, x, y);
     }
   }
 
@@ -557,7 +601,7 @@ impl Pattern {
   /// Since 1.12
   pub fn set_corner_color_rgb(&mut self, corner_num: i32, red: f64, green: f64, blue: f64) {
     unsafe {
-      cairo_mesh_pattern_set_corner_color_rgb(self.opaque, corner_num, red, green, blue);
+      ffi::cairo_mesh_pattern_set_corner_color_rgb(self.opaque, corner_num as libc::c_uint, red, green, blue);
     }
   }
 
@@ -585,10 +629,21 @@ impl Pattern {
   /// Since 1.12
   pub fn set_corner_color_rgba(&mut self, corner_num: i32, red: f64, green: f64, blue: f64, alpha: f64) {
     unsafe {
-      cairo_mesh_pattern_set_corner_color_rgba(self.opaque, corner_num, red, green, blue, alpha);
+      ffi::cairo_mesh_pattern_set_corner_color_rgba(self.opaque, corner_num as libc::c_uint, red, green, blue, alpha);
     }
   }
 
+  /// Sets the color of a corner of the current patch in a mesh pattern, see cairo_mesh_pattern_set_corner_color_rgba().
+  ///
+  /// pattern : a pattern::Pattern
+  ///
+  /// corner_num : the corner to set the color for
+  ///
+  /// color : the color of the corner
+  pub fn set_corner_color(&mut self, corner_num: i32, color: Color) {
+    self.set_corner_color_rgba(corner_num, color.red, color.green, color.blue, color.alpha);
+  }
+
   /// Gets the number of patches specified in the given mesh pattern.
   /// 
   /// The number only includes patches which have been finished by calling cairo_mesh_pattern_end_patch(). For example it will be 0 during the definition of the first patch.
@@ -602,9 +657,9 @@ impl Pattern {
   /// Since 1.12
   pub fn get_patch_count(&mut self) -> (super::Status, i32) {
     unsafe {
-      let mut count:i32 = std::intrinsics::init();
-      let foreign_result = cairo_mesh_pattern_get_patch_count(self.opaque, &mut count);
-      return (foreign_result, count);
+      let mut count:libc::c_uint = std::intrinsics::init();
+      let foreign_result = std::mem::transmute(ffi::cairo_mesh_pattern_get_patch_count(self.opaque, &mut count));
+      return (foreign_result, count as i32);
     }
   }
 
@@ -621,7 +676,7 @@ impl Pattern {
   /// Since 1.12
   pub fn get_path(&mut self, patch_num: i32) -> super::path::Path {
     unsafe {
-      let foreign_result = cairo_mesh_pattern_get_path(self.opaque, patch_num);
+      let foreign_result = super::path::Path { opaque: ffi::cairo_mesh_pattern_get_path(self.opaque, patch_num as libc::c_uint) as *mut libc::c_void };
       return foreign_result;
     }
   }
@@ -649,7 +704,7 @@ impl Pattern {
     unsafe {
       let mut x:f64 = std::intrinsics::init();
       let mut y:f64 = std::intrinsics::init();
-      let foreign_result = cairo_mesh_pattern_get_control_point(self.opaque, patch_num, pointer_num, &mut x, &mut y);
+      let foreign_result = std::mem::transmute(ffi::cairo_mesh_pattern_get_control_point(self.opaque, patch_num as libc::c_uint, pointer_num as libc::c_uint, &mut x, &mut y));
       return (foreign_result, x, y);
     }
   }
@@ -683,11 +738,48 @@ impl Pattern {
       let mut green:f64 = std::intrinsics::init();
       let mut blue:f64 = std::intrinsics::init();
       let mut alpha:f64 = std::intrinsics::init();
-      let foreign_result = cairo_mesh_pattern_get_corner_color_rgba(self.opaque, patch_num, pointer_num, &mut red, &mut green, &mut blue, &mut alpha);
+      let foreign_result = std::mem::transmute(ffi::cairo_mesh_pattern_get_corner_color_rgba(self.opaque, patch_num as libc::c_uint, pointer_num as libc::c_uint, &mut red, &mut green, &mut blue, &mut alpha));
       return (foreign_result, red, green, blue, alpha);
     }
   }
 
+  /// Creates a new user pattern for providing pixel data.
+  ///
+  /// Use this function to create a pattern whose pixels are supplied on demand by source, see pattern::raster_source::RasterSource. Cairo only asks for the areas it actually samples, so large images can be decoded lazily.
+  ///
+  /// source is owned by the pattern and dropped when the last reference to the pattern, including any internal copies made by cairo, is released.
+  ///
+  /// content : content type for the pixel data that will be returned. Knowing the content type ahead of time is used for analysing the operation and picking the appropriate rendering path.
+  ///
+  /// width : maximum size of the sample area
+  ///
+  /// height : maximum size of the sample area
+  ///
+  /// source : the pattern::raster_source::RasterSource providing the pixel data
+  ///
+  /// Returns : a newly created pattern::Pattern of type pattern::pattern_type::RasterSource. This function will always return a valid pointer, but if an error occurred the pattern status will be set to an error. To inspect the status of a pattern use cairo_pattern_status().
+  ///
+  /// Since 1.12
+  pub fn raster_source<T: raster_source::RasterSource + 'static>(content: super::surface::content::Content, width: i32, height: i32, source: T) -> Pattern {
+    unsafe {
+      let data: Box<RasterSourceData<T>> = box RasterSourceData { source: source, references: AtomicUint::new(1) };
+      let callback_data: *mut libc::c_void = std::mem::transmute(data);
+      let mut foreign_result = Pattern { opaque: ffi::cairo_pattern_create_raster_source(callback_data, content as ffi::cairo_content_t, width, height) };
+      match foreign_result.status() {
+        super::Success => {
+          ffi::cairo_raster_source_pattern_set_acquire(foreign_result.opaque, raster_source_acquire::<T>, raster_source_release::<T>);
+          ffi::cairo_raster_source_pattern_set_copy(foreign_result.opaque, raster_source_copy::<T>);
+          ffi::cairo_raster_source_pattern_set_finish(foreign_result.opaque, raster_source_finish::<T>);
+        },
+        _ => {
+          // The nil pattern never calls back, so the source is dropped here.
+          let _data: Box<RasterSourceData<T>> = std::mem::transmute(callback_data);
+        }
+      }
+      return foreign_result;
+    }
+  }
+
   /// Checks whether an error has previously occurred for this pattern.
   /// 
   /// pattern : a pattern::Pattern
@@ -697,7 +789,7 @@ impl Pattern {
   /// Since 1.0
   pub fn status(&mut self) -> super::Status {
     unsafe {
-      let foreign_result = cairo_pattern_status(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_pattern_status(self.opaque));
       return foreign_result;
     }
   }
@@ -713,7 +805,7 @@ impl Pattern {
   /// Since 1.0
   pub fn set_extend(&mut self, extend: extend::Extend) {
     unsafe {
-      cairo_pattern_set_extend(self.opaque, extend);
+      ffi::cairo_pattern_set_extend(self.opaque, extend as ffi::cairo_extend_t);
     }
   }
 
@@ -727,7 +819,7 @@ impl Pattern {
   /// Since 1.0
   pub fn get_extend(&mut self) -> extend::Extend {
     unsafe {
-      let foreign_result = cairo_pattern_get_extend(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_pattern_get_extend(self.opaque));
       return foreign_result;
     }
   }
@@ -748,7 +840,7 @@ impl Pattern {
   /// Since 1.0
   pub fn set_filter(&mut self, filter: filter::Filter) {
     unsafe {
-      cairo_pattern_set_filter(self.opaque, filter);
+      ffi::cairo_pattern_set_filter(self.opaque, filter as ffi::cairo_filter_t);
     }
   }
 
@@ -761,7 +853,36 @@ impl Pattern {
   /// Since 1.0
   pub fn get_filter(&mut self) -> filter::Filter {
     unsafe {
-      let foreign_result = cairo_pattern_get_filter(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_pattern_get_filter(self.opaque));
+      return foreign_result;
+    }
+  }
+
+  /// Set the dithering mode of the rasterizer used for drawing shapes. This value is a hint, and a particular backend may or may not support a particular value. At the current time, only pixman is supported.
+  ///
+  /// pattern : a pattern::Pattern
+  ///
+  /// dither : a pattern::dither::Dither describing the dithering mode
+  ///
+  /// Since 1.18
+  #[cfg(feature = "v1_18")]
+  pub fn set_dither(&mut self, dither: dither::Dither) {
+    unsafe {
+      ffi::cairo_pattern_set_dither(self.opaque, dither as ffi::cairo_dither_t);
+    }
+  }
+
+  /// Gets the current dithering mode, as set by cairo_pattern_set_dither().
+  ///
+  /// pattern : a pattern::Pattern
+  ///
+  /// Returns : the current dithering mode
+  ///
+  /// Since 1.18
+  #[cfg(feature = "v1_18")]
+  pub fn get_dither(&mut self) -> dither::Dither {
+    unsafe {
+      let foreign_result = std::mem::transmute(ffi::cairo_pattern_get_dither(self.opaque));
       return foreign_result;
     }
   }
@@ -790,7 +911,7 @@ impl Pattern {
   /// Since 1.0
   pub fn set_matrix(&mut self, matrix: super::matrix::Matrix) {
     unsafe {
-      cairo_pattern_set_matrix(self.opaque, matrix);
+      ffi::cairo_pattern_set_matrix(self.opaque, &matrix as *super::matrix::Matrix as *ffi::cairo_matrix_t);
     }
   }
 
@@ -803,8 +924,9 @@ impl Pattern {
   /// Since 1.0
   pub fn get_matrix(&mut self) -> super::matrix::Matrix {
     unsafe {
-      let foreign_result = cairo_pattern_get_matrix(self.opaque);
-      return foreign_result;
+      let mut matrix:super::matrix::Matrix = std::intrinsics::init();
+      ffi::cairo_pattern_get_matrix(self.opaque, &mut matrix as *mut super::matrix::Matrix as *mut ffi::cairo_matrix_t);
+      return matrix;
     }
   }
 
@@ -817,7 +939,7 @@ impl Pattern {
   /// Since 1.2
   pub fn get_type(&mut self) -> pattern_type::PatternType {
     unsafe {
-      let foreign_result = cairo_pattern_get_type(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_pattern_get_type(self.opaque));
       return foreign_result;
     }
   }
@@ -831,76 +953,130 @@ impl Pattern {
   /// Since 1.4
   pub fn reference_count(&mut self) -> i32 {
     unsafe {
-      let foreign_result = cairo_pattern_get_reference_count(self.opaque);
+      let foreign_result = ffi::cairo_pattern_get_reference_count(self.opaque) as i32;
+      return foreign_result;
+    }
+  }
+
+  /// Attach user data to pattern. To remove user data from pattern, call pattern::Pattern::remove_user_data() with the same key. The data is dropped when it is replaced or removed, or when the last reference to pattern is destroyed.
+  ///
+  /// pattern : a pattern::Pattern
+  ///
+  /// key : the address of a user_data::Key to attach the user data to
+  ///
+  /// data : the user data to attach to pattern
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or CAIRO_STATUS_NO_MEMORY if a slot could not be allocated for the user data.
+  ///
+  /// Since 1.4
+  pub fn set_user_data<T: 'static>(&mut self, key: &'static super::user_data::Key, data: T) -> super::Status {
+    unsafe {
+      let foreign_result = super::attach_user_data(self.opaque, ffi::cairo_pattern_set_user_data, key, Some(data));
+      return foreign_result;
+    }
+  }
+
+  /// Remove the user data attached to pattern with key, dropping it.
+  ///
+  /// pattern : a pattern::Pattern
+  ///
+  /// key : the address of the user_data::Key the user data was attached to
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or the error status of pattern.
+  ///
+  /// Since 1.4
+  pub fn remove_user_data(&mut self, key: &'static super::user_data::Key) -> super::Status {
+    unsafe {
+      let foreign_result = super::attach_user_data::<()>(self.opaque, ffi::cairo_pattern_set_user_data, key, None);
+      return foreign_result;
+    }
+  }
+
+  /// Return user data previously attached to pattern using the specified key. If no user data has been attached with the given key, or it is not a T, this function returns None. The user data is cloned, since other references to the object may replace or remove it.
+  ///
+  /// pattern : a pattern::Pattern
+  ///
+  /// key : the address of the user_data::Key the user data was attached to
+  ///
+  /// Returns : a clone of the user data previously attached or None.
+  ///
+  /// Since 1.4
+  pub fn get_user_data<T: Clone + 'static>(&mut self, key: &'static super::user_data::Key) -> Option<T> {
+    unsafe {
+      let foreign_result = super::find_user_data(self.opaque, ffi::cairo_pattern_get_user_data, key);
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_pattern_add_color_stop_rgb(self_value: *mut libc::c_void, offset: f64, red: f64, green: f64, blue: f64);
-  fn cairo_pattern_add_color_stop_rgba(self_value: *mut libc::c_void, offset: f64, red: f64, green: f64, blue: f64, alpha: f64);
-  fn cairo_pattern_get_color_stop_count(self_value: *mut libc::c_void, stop_count: *mut i32) -> super::Status;
-  fn cairo_pattern_get_color_stop_rgba(self_value: *mut libc::c_void, stop_count: i32, offset: *mut f64, red: *mut f64, green: *mut f64, blue: *mut f64, alpha: *mut f64) -> super::Status;
-  fn cairo_pattern_create_rgb(red: f64, green: f64, blue: f64) -> Pattern;
-  fn cairo_pattern_create_rgba(red: f64, green: f64, blue: f64, alpha: f64) -> Pattern;
-  fn cairo_pattern_get_rgba(self_value: *mut libc::c_void, red: *mut f64, green: *mut f64, blue: *mut f64, alpha: *mut f64) -> super::Status;
-  fn cairo_pattern_create_for_surface(surface: super::surface::Surface) -> Pattern;
-  fn cairo_pattern_get_surface(self_value: *mut libc::c_void, surface: *mut super::surface::Surface) -> super::Status;
-  fn cairo_pattern_create_linear(x0: f64, y0: f64, x1: f64, y1: f64) -> Pattern;
-  fn cairo_pattern_get_linear_points(self_value: *mut libc::c_void, x0: *mut f64, y0: *mut f64, x1: *mut f64, y1: *mut f64) -> super::Status;
-  fn cairo_pattern_create_radial(cx0: f64, cy0: f64, radius0: f64, cx1: f64, cy1: f64, radius1: f64) -> Pattern;
-  fn cairo_pattern_get_radial_circles(self_value: *mut libc::c_void, x0: *mut f64, y0: *mut f64, r0: *mut f64, x1: *mut f64, y1: *mut f64, r1: *mut f64) -> super::Status;
-  fn cairo_pattern_create_mesh() -> Pattern;
-  fn cairo_mesh_pattern_begin_patch(self_value: *mut libc::c_void);
-  fn cairo_mesh_pattern_end_patch(self_value: *mut libc::c_void);
-  fn cairo_mesh_pattern_move_to(self_value: *mut libc::c_void, x: f64, y: f64);
-  fn cairo_mesh_pattern_line_to(self_value: *mut libc::c_void, x: f64, y: f64);
-  fn cairo_mesh_pattern_curve_to(self_value: *mut libc::c_void, x1: f64, y1: f64, x2: f64, y2: f64, x3: f64, y3: f64);
-  fn cairo_mesh_pattern_set_control_point(self_value: *mut libc::c_void, point_num: i32, x: f64, y: f64);
-  fn cairo_mesh_pattern_set_corner_color_rgb(self_value: *mut libc::c_void, corner_num: i32, red: f64, green: f64, blue: f64);
-  fn cairo_mesh_pattern_set_corner_color_rgba(self_value: *mut libc::c_void, corner_num: i32, red: f64, green: f64, blue: f64, alpha: f64);
-  fn cairo_mesh_pattern_get_patch_count(self_value: *mut libc::c_void, count: *mut i32) -> super::Status;
-  fn cairo_mesh_pattern_get_path(self_value: *mut libc::c_void, patch_num: i32) -> super::path::Path;
-  fn cairo_mesh_pattern_get_control_point(self_value: *mut libc::c_void, patch_num: i32, pointer_num: i32, x: *mut f64, y: *mut f64) -> super::Status;
-  fn cairo_mesh_pattern_get_corner_color_rgba(self_value: *mut libc::c_void, patch_num: i32, pointer_num: i32, red: *mut f64, green: *mut f64, blue: *mut f64, alpha: *mut f64) -> super::Status;
-  fn cairo_pattern_status(self_value: *mut libc::c_void) -> super::Status;
-  fn cairo_pattern_set_extend(self_value: *mut libc::c_void, extend: extend::Extend);
-  fn cairo_pattern_get_extend(self_value: *mut libc::c_void) -> extend::Extend;
-  fn cairo_pattern_set_filter(self_value: *mut libc::c_void, filter: filter::Filter);
-  fn cairo_pattern_get_filter(self_value: *mut libc::c_void) -> filter::Filter;
-  fn cairo_pattern_set_matrix(self_value: *mut libc::c_void, matrix: super::matrix::Matrix);
-  fn cairo_pattern_get_matrix(self_value: *mut libc::c_void) -> super::matrix::Matrix;
-  fn cairo_pattern_get_type(self_value: *mut libc::c_void) -> pattern_type::PatternType;
-  fn cairo_pattern_get_reference_count(self_value: *mut libc::c_void) -> i32;
+
+/// Callback data of a raster source pattern. Cairo shares it between a pattern and its internal copies, so it is reference counted and dropped by the last finish callback.
+struct RasterSourceData<T> {
+  source: T,
+  references: AtomicUint
+}
+
+extern "C" fn raster_source_acquire<T: raster_source::RasterSource>(_pattern: *mut libc::c_void, callback_data: *mut libc::c_void, _target: *mut libc::c_void, extents: *ffi::cairo_rectangle_int_t) -> *mut libc::c_void {
+  unsafe {
+    let data: &mut RasterSourceData<T> = std::mem::transmute(callback_data);
+    let extents = &*extents;
+    let surface = data.source.acquire(extents.x, extents.y, extents.width, extents.height);
+    let opaque = surface.opaque;
+    // Ownership of the surface passes to cairo until it is handed back to raster_source_release().
+    std::mem::forget(surface);
+    return opaque;
+  }
+}
+
+extern "C" fn raster_source_release<T: raster_source::RasterSource>(_pattern: *mut libc::c_void, callback_data: *mut libc::c_void, surface: *mut libc::c_void) {
+  unsafe {
+    let data: &mut RasterSourceData<T> = std::mem::transmute(callback_data);
+    data.source.release(super::surface::Surface { opaque: surface });
+  }
+}
+
+extern "C" fn raster_source_copy<T: raster_source::RasterSource>(_pattern: *mut libc::c_void, callback_data: *mut libc::c_void, _other: *libc::c_void) -> ffi::cairo_status_t {
+  unsafe {
+    let data: &mut RasterSourceData<T> = std::mem::transmute(callback_data);
+    data.references.fetch_add(1, SeqCst);
+    return super::Success as ffi::cairo_status_t;
+  }
+}
+
+extern "C" fn raster_source_finish<T: raster_source::RasterSource>(_pattern: *mut libc::c_void, callback_data: *mut libc::c_void) {
+  unsafe {
+    let data: &mut RasterSourceData<T> = std::mem::transmute(callback_data);
+    if data.references.fetch_sub(1, SeqCst) == 1 {
+      let _data: Box<RasterSourceData<T>> = std::mem::transmute(callback_data);
+    }
+  }
 }
 
 impl std::clone::Clone for Pattern {
   fn clone(&self) -> Pattern {
     unsafe {
-      let foreign_result = cairo_pattern_reference(self.opaque as *libc::c_void);
+      let foreign_result = Pattern { opaque: ffi::cairo_pattern_reference(self.opaque) };
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_pattern_reference(self_value: *libc::c_void) -> Pattern;
-}
 
 impl std::ops::Drop for Pattern {
   fn drop(&mut self) {
     unsafe {
-      cairo_pattern_destroy(self.opaque);
+      ffi::cairo_pattern_destroy(self.opaque);
     }
   }
 }
 
-extern {
-  fn cairo_pattern_destroy(self_value: *mut libc::c_void);
-}
 
+pub mod css;
+pub mod dither;
 pub mod extend;
 pub mod filter;
+pub mod gradient;
+pub mod mesh;
 pub mod pattern_type;
+pub mod raster_source;
 
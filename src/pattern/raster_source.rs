@@ -0,0 +1,26 @@
+//! Supply pixel data for raster source patterns on demand
+
+use super::super::surface::Surface;
+
+/// A pattern::raster_source::RasterSource supplies the pixel data of a raster source pattern, see pattern::Pattern::raster_source().
+///
+/// Cairo calls acquire() whenever it needs to read pixels from the pattern, passing the extents of the area it is about to sample, in pattern space. This allows large images to be decoded lazily, only for the regions that are actually drawn. The surface returned by acquire() is handed back to release() once cairo has finished reading from it.
+///
+/// Since 1.12
+pub trait RasterSource {
+  /// Returns a surface containing the pixels of the given area of the pattern. The surface may cover more than the requested area; its device offset determines where it is placed in pattern space, see cairo_surface_set_device_offset().
+  ///
+  /// x : X coordinate of the area to sample
+  ///
+  /// y : Y coordinate of the area to sample
+  ///
+  /// width : width of the area to sample
+  ///
+  /// height : height of the area to sample
+  fn acquire(&mut self, x: i32, y: i32, width: i32, height: i32) -> Surface;
+
+  /// Called when cairo is done reading from a surface returned by acquire(). The default implementation drops the surface.
+  ///
+  /// surface : the surface returned by acquire()
+  fn release(&mut self, _surface: Surface) {}
+}
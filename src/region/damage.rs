@@ -0,0 +1,105 @@
+//! Track damaged areas for partial redraws
+
+use std;
+use super::{Region, Rectangle};
+use super::super::{Cairo, Status};
+use super::super::surface::Surface;
+
+/// A region::damage::DamageTracker accumulates the areas of a surface that need to be redrawn.
+///
+/// Damaged rectangles are merged into a region::Region, which keeps them as a minimal set of non-overlapping bands. When the region grows beyond max_rectangles, it is collapsed into its extents, trading some overdraw for fewer clip operations.
+pub struct DamageTracker {
+  region: Region,
+  max_rectangles: i32
+}
+
+impl DamageTracker {
+  /// Creates a tracker without any damage, which never collapses its region.
+  pub fn new() -> DamageTracker {
+    DamageTracker::with_max_rectangles(0)
+  }
+
+  /// Creates a tracker without any damage, which collapses its region into the extents once it holds more than max_rectangles rectangles.
+  ///
+  /// max_rectangles : the number of rectangles to keep before collapsing, or 0 to never collapse
+  pub fn with_max_rectangles(max_rectangles: i32) -> DamageTracker {
+    DamageTracker { region: Region::new(), max_rectangles: max_rectangles }
+  }
+
+  /// Marks rectangle as damaged. Empty rectangles are ignored.
+  ///
+  /// rectangle : the damaged region::Rectangle
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or CAIRO_STATUS_NO_MEMORY
+  pub fn add(&mut self, rectangle: &Rectangle) -> Status {
+    if rectangle.is_empty() {
+      return super::super::Success;
+    }
+    match self.region.union_rectangle(rectangle) {
+      super::super::Success => self.coalesce(),
+      error => error
+    }
+  }
+
+  /// Marks all of region as damaged.
+  ///
+  /// region : the damaged region::Region
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or CAIRO_STATUS_NO_MEMORY
+  pub fn add_region(&mut self, region: &Region) -> Status {
+    match self.region.union(region) {
+      super::super::Success => self.coalesce(),
+      error => error
+    }
+  }
+
+  /// Checks whether anything needs to be redrawn.
+  pub fn is_empty(&mut self) -> bool {
+    self.region.is_empty()
+  }
+
+  /// Returns the damaged region.
+  pub fn region<'a>(&'a mut self) -> &'a mut Region {
+    &mut self.region
+  }
+
+  /// Forgets all damage.
+  pub fn clear(&mut self) {
+    self.region = Region::new();
+  }
+
+  /// Redraws every damaged rectangle and forgets all damage.
+  ///
+  /// For each rectangle the context is saved, clipped to the rectangle and passed to draw, then restored. The rectangles are in device space, so cr should have an identity transformation and target surface, or a surface whose contents are copied onto it.
+  ///
+  /// Besides drawing with cr, draw may write the pixels of the rectangle directly, through the data of the target returned by Cairo::get_target(). So surface is flushed before draw is called, and afterwards the rectangle is marked dirty with cairo_surface_mark_dirty_rectangle(), which makes cairo drop any copy of those pixels it keeps.
+  ///
+  /// cr : the cairo context to draw with
+  ///
+  /// surface : the surface receiving the redrawn pixels
+  ///
+  /// draw : called with the clipped context and the rectangle being redrawn
+  pub fn redraw(&mut self, cr: &mut Cairo, surface: &mut Surface, draw: |&mut Cairo, &Rectangle|) {
+    let mut region = std::mem::replace(&mut self.region, Region::new());
+    for rectangle in region.iter() {
+      cr.save();
+      // The path is not part of the saved state, so clear it before building the clip.
+      cr.new_path();
+      cr.rectangle(rectangle.x as f64, rectangle.y as f64, rectangle.width as f64, rectangle.height as f64);
+      cr.clip();
+      surface.flush();
+      draw(cr, &rectangle);
+      cr.restore();
+      surface.mark_dirty_rectangle(rectangle.x, rectangle.y, rectangle.width, rectangle.height);
+    }
+  }
+
+  /// Collapses the region into its extents when it holds too many rectangles.
+  fn coalesce(&mut self) -> Status {
+    if self.max_rectangles > 0 && self.region.num_rectangles() > self.max_rectangles {
+      let extents = self.region.get_extents();
+      self.region = Region::rectangle(&extents);
+    }
+    return self.region.status();
+  }
+}
@@ -3,6 +3,10 @@
 use std;
 use super::DeepClone;
 use libc;
+use ffi;
+
+/// The largest width and height of the mask surface::Surface rasterized by region::Region::from_path(), which is the largest image surface cairo creates.
+static MAX_MASK_SIZE: i32 = 32767;
 
 /// A region::Region represents a set of integer-aligned rectangles.
 /// 
@@ -19,15 +23,98 @@ pub struct Region {
 /// A data structure for holding a rectangle with integer coordinates.
 /// 
 /// Since 1.10
+#[deriving(Clone, PartialEq, Show)]
+#[cfg_attr(feature = "serde", deriving(Encodable, Decodable))]
 pub struct Rectangle {
   /// X coordinate of the left side of the rectangle
-  x: i32,
+  pub x: i32,
   /// Y coordinate of the the top side of the rectangle
-  y: i32,
+  pub y: i32,
   /// width of the rectangle
-  width: i32,
+  pub width: i32,
   /// height of the rectangle
-  height: i32
+  pub height: i32
+}
+
+impl Rectangle {
+  /// Creates a rectangle.
+  ///
+  /// x : X coordinate of the left side of the rectangle
+  ///
+  /// y : Y coordinate of the the top side of the rectangle
+  ///
+  /// width : width of the rectangle
+  ///
+  /// height : height of the rectangle
+  pub fn new(x: i32, y: i32, width: i32, height: i32) -> Rectangle {
+    Rectangle { x: x, y: y, width: width, height: height }
+  }
+
+  /// Checks whether the rectangle covers no pixels.
+  ///
+  /// Returns : true if the width or height of the rectangle is not positive.
+  pub fn is_empty(&self) -> bool {
+    self.width <= 0 || self.height <= 0
+  }
+
+  /// Computes the area covered by both rectangles.
+  ///
+  /// other : another region::Rectangle
+  ///
+  /// Returns : the intersection, or None if the rectangles do not overlap.
+  pub fn intersection(&self, other: &Rectangle) -> Option<Rectangle> {
+    let x = std::cmp::max(self.x, other.x);
+    let y = std::cmp::max(self.y, other.y);
+    let right = std::cmp::min(self.x + self.width, other.x + other.width);
+    let bottom = std::cmp::min(self.y + self.height, other.y + other.height);
+    let intersection = Rectangle::new(x, y, right - x, bottom - y);
+    if intersection.is_empty() {
+      return None;
+    }
+    return Some(intersection);
+  }
+
+  /// Computes the smallest rectangle containing both rectangles. An empty rectangle does not contribute to the result.
+  ///
+  /// other : another region::Rectangle
+  ///
+  /// Returns : the bounding rectangle of both rectangles.
+  pub fn union(&self, other: &Rectangle) -> Rectangle {
+    if other.is_empty() {
+      return self.clone();
+    }
+    if self.is_empty() {
+      return other.clone();
+    }
+    let x = std::cmp::min(self.x, other.x);
+    let y = std::cmp::min(self.y, other.y);
+    let right = std::cmp::max(self.x + self.width, other.x + other.width);
+    let bottom = std::cmp::max(self.y + self.height, other.y + other.height);
+    return Rectangle::new(x, y, right - x, bottom - y);
+  }
+}
+
+/// An iterator over the rectangles of a region, returned by region::Region::iter().
+pub struct Rectangles<'a> {
+  region: &'a mut Region,
+  index: i32,
+  count: i32
+}
+
+impl<'a> Iterator<Rectangle> for Rectangles<'a> {
+  fn next(&mut self) -> Option<Rectangle> {
+    if self.index >= self.count {
+      return None;
+    }
+    let rectangle = self.region.get_rectangle(self.index);
+    self.index += 1;
+    return Some(rectangle);
+  }
+
+  fn size_hint(&self) -> (uint, Option<uint>) {
+    let remaining = (self.count - self.index) as uint;
+    (remaining, Some(remaining))
+  }
 }
 
 impl Region {
@@ -38,7 +125,7 @@ impl Region {
   /// Since 1.10
   pub fn new() -> Region {
     unsafe {
-      let foreign_result = cairo_region_create();
+      let foreign_result = Region { opaque: ffi::cairo_region_create() };
       return foreign_result;
     }
   }
@@ -52,7 +139,7 @@ impl Region {
   /// Since 1.10
   pub fn rectangle(rectangle: &Rectangle) -> Region {
     unsafe {
-      let foreign_result = cairo_region_create_rectangle(rectangle);
+      let foreign_result = Region { opaque: ffi::cairo_region_create_rectangle(rectangle as *Rectangle as *ffi::cairo_rectangle_int_t) };
       return foreign_result;
     }
   }
@@ -68,11 +155,100 @@ impl Region {
   /// Since 1.10
   pub fn rectangles(rectangles: &[Rectangle]) -> Region {
     unsafe {
-      let foreign_result = cairo_region_create_rectangles(rectangles.as_ptr(), rectangles.len() as i32);
+      let foreign_result = Region { opaque: ffi::cairo_region_create_rectangles(rectangles.as_ptr() as *Rectangle as *ffi::cairo_rectangle_int_t, rectangles.len() as i32) };
       return foreign_result;
     }
   }
 
+  /// Allocates a new region object containing the pixels covered by filling path. The path is rasterized without antialiasing into an A1 image surface, so a pixel is part of the region when its center is inside the path. Path coordinates are taken as device pixels.
+  ///
+  /// path : the path to fill
+  ///
+  /// fill_rule : the fill rule used to determine which parts of path are inside
+  ///
+  /// tolerance : the tolerance used when converting curves into line segments, see cairo_set_tolerance()
+  ///
+  /// Returns : A newly allocated region::Region, or CAIRO_STATUS_INVALID_SIZE if the extents of the filled path are not finite or wider or higher than the largest image surface, 32767 pixels, or the error status of the mask surface if cairo failed to create it.
+  pub fn from_path(path: &super::path::Path, fill_rule: super::fill_rule::FillRule, tolerance: f64) -> Result<Region, super::Status> {
+    let mut probe = super::surface::Surface::image(super::surface::format::A1, 1, 1);
+    let (x1, y1, x2, y2) = {
+      let mut cr = super::Cairo::new(&mut probe);
+      cr.set_fill_rule(fill_rule);
+      cr.set_tolerance(tolerance);
+      cr.append_path(path);
+      cr.fill_extents()
+    };
+    if !(x1.is_finite() && y1.is_finite() && x2.is_finite() && y2.is_finite()) {
+      return Err(super::InvalidSize);
+    }
+    let (x1, y1, x2, y2) = (x1.floor(), y1.floor(), x2.ceil(), y2.ceil());
+    if x2 <= x1 || y2 <= y1 {
+      return Ok(Region::new());
+    }
+    // The mask covers the extents, so bound them before allocating it, and keep the offsets within i32.
+    if x2 - x1 > MAX_MASK_SIZE as f64 || y2 - y1 > MAX_MASK_SIZE as f64 || x1 < std::i32::MIN as f64 || y1 < std::i32::MIN as f64 || x2 > std::i32::MAX as f64 || y2 > std::i32::MAX as f64 {
+      return Err(super::InvalidSize);
+    }
+    let x = x1 as i32;
+    let y = y1 as i32;
+    let width = (x2 - x1) as i32;
+    let height = (y2 - y1) as i32;
+    let mut mask = super::surface::Surface::image(super::surface::format::A1, width, height);
+    match mask.status() {
+      super::Success => {},
+      error => return Err(error)
+    }
+    {
+      let mut cr = super::Cairo::new(&mut mask);
+      cr.translate(-x as f64, -y as f64);
+      cr.set_antialias(super::antialias::None);
+      cr.set_fill_rule(fill_rule);
+      cr.set_tolerance(tolerance);
+      cr.append_path(path);
+      cr.fill();
+    }
+    let mut region = Region::from_a1_surface(&mut mask);
+    region.translate(x, y);
+    return Ok(region);
+  }
+
+  /// Allocates a new region object containing the set pixels of an A1 image surface, in surface coordinates. The surface is flushed before its data is read.
+  ///
+  /// surface : an image surface::Surface of format CAIRO_FORMAT_A1
+  ///
+  /// Returns : A newly allocated region::Region, which is empty if surface is not an A1 image surface.
+  pub fn from_a1_surface(surface: &mut super::surface::Surface) -> Region {
+    match surface.get_format() {
+      super::surface::format::A1 => {},
+      _ => return Region::new()
+    }
+    surface.flush();
+    let width = surface.get_width();
+    let height = surface.get_height();
+    let stride = surface.get_stride() as uint;
+    let data = surface.get_data();
+    if data.len() < stride * height as uint {
+      return Region::new();
+    }
+    let mut rectangles = Vec::new();
+    for y in range(0, height) {
+      let row = data.slice(y as uint * stride, (y as uint + 1) * stride);
+      let mut start = None;
+      for x in range(0, width + 1) {
+        let set = x < width && a1_pixel(row, x as uint);
+        match (set, start) {
+          (true, None) => start = Some(x),
+          (false, Some(first)) => {
+            rectangles.push(Rectangle::new(first, y, x - first, 1));
+            start = None;
+          },
+          _ => {}
+        }
+      }
+    }
+    return Region::rectangles(rectangles.as_slice());
+  }
+
   /// Checks whether an error has previous occurred for this region object.
   /// 
   /// region : a region::Region
@@ -82,7 +258,7 @@ impl Region {
   /// Since 1.10
   pub fn status(&mut self) -> super::Status {
     unsafe {
-      let foreign_result = cairo_region_status(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_region_status(self.opaque));
       return foreign_result;
     }
   }
@@ -97,7 +273,7 @@ impl Region {
   pub fn get_extents(&mut self) -> Rectangle {
     unsafe {
       let mut extents:Rectangle = std::intrinsics::init();
-      cairo_region_get_extents(self.opaque, &mut extents);
+      ffi::cairo_region_get_extents(self.opaque, &mut extents as *mut Rectangle as *mut ffi::cairo_rectangle_int_t);
       return extents;
     }
   }
@@ -111,7 +287,7 @@ impl Region {
   /// Since 1.10
   pub fn num_rectangles(&mut self) -> i32 {
     unsafe {
-      let foreign_result = cairo_region_num_rectangles(self.opaque);
+      let foreign_result = ffi::cairo_region_num_rectangles(self.opaque);
       return foreign_result;
     }
   }
@@ -128,11 +304,19 @@ impl Region {
   pub fn get_rectangle(&mut self, nth: i32) -> Rectangle {
     unsafe {
       let mut rectangle:Rectangle = std::intrinsics::init();
-      cairo_region_get_rectangle(self.opaque, nth, &mut rectangle);
+      ffi::cairo_region_get_rectangle(self.opaque, nth, &mut rectangle as *mut Rectangle as *mut ffi::cairo_rectangle_int_t);
       return rectangle;
     }
   }
 
+  /// Returns an iterator over the rectangles contained in region, see cairo_region_get_rectangle().
+  ///
+  /// region : a region::Region
+  pub fn iter<'a>(&'a mut self) -> Rectangles<'a> {
+    let count = self.num_rectangles();
+    Rectangles { region: self, index: 0, count: count }
+  }
+
   /// Checks whether region is empty.
   /// 
   /// region : a region::Region
@@ -142,7 +326,7 @@ impl Region {
   /// Since 1.1
   pub fn is_empty(&mut self) -> bool {
     unsafe {
-      let foreign_result = cairo_region_is_empty(self.opaque);
+      let foreign_result = ffi::cairo_region_is_empty(self.opaque);
       return foreign_result != 0;
     }
   }
@@ -160,7 +344,7 @@ impl Region {
   /// Since 1.10
   pub fn contains_point(&mut self, x: i32, y: i32) -> bool {
     unsafe {
-      let foreign_result = cairo_region_contains_point(self.opaque, x, y);
+      let foreign_result = ffi::cairo_region_contains_point(self.opaque, x, y);
       return foreign_result != 0;
     }
   }
@@ -176,7 +360,7 @@ impl Region {
   /// Since 1.10
   pub fn containts_rectangle(&mut self, rectangle: &Rectangle) -> overlap::Overlap {
     unsafe {
-      let foreign_result = cairo_region_contains_rectangle(self.opaque, rectangle);
+      let foreign_result = std::mem::transmute(ffi::cairo_region_contains_rectangle(self.opaque, rectangle as *Rectangle as *ffi::cairo_rectangle_int_t));
       return foreign_result;
     }
   }
@@ -192,7 +376,7 @@ impl Region {
   /// Since 1.10
   pub fn equal(&mut self, other: &Region) -> bool {
     unsafe {
-      let foreign_result = cairo_region_equal(self.opaque, other.opaque as *libc::c_void);
+      let foreign_result = ffi::cairo_region_equal(self.opaque, other.opaque);
       return foreign_result != 0;
     }
   }
@@ -208,7 +392,23 @@ impl Region {
   /// Since 1.10
   pub fn translate(&mut self, dx: i32, dy: i32) {
     unsafe {
-      cairo_region_translate(self.opaque, dx, dy);
+      ffi::cairo_region_translate(self.opaque, dx, dy);
+    }
+  }
+
+  /// Computes the intersection of dst with other and places the result in dst
+  /// 
+  /// dst : a region::Region
+  ///
+  /// other : another region::Region
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or CAIRO_STATUS_NO_MEMORY
+  ///
+  /// Since 1.10
+  pub fn intersect(&mut self, region: &Region) -> super::Status {
+    unsafe {
+      let foreign_result = std::mem::transmute(ffi::cairo_region_intersect(self.opaque, region.opaque));
+      return foreign_result;
     }
   }
 
@@ -223,7 +423,7 @@ impl Region {
   /// Since 1.10
   pub fn intersect_rectangle(&mut self, rectangle: &Rectangle) -> super::Status {
     unsafe {
-      let foreign_result = cairo_region_intersect_rectangle(self.opaque, rectangle);
+      let foreign_result = std::mem::transmute(ffi::cairo_region_intersect_rectangle(self.opaque, rectangle as *Rectangle as *ffi::cairo_rectangle_int_t));
       return foreign_result;
     }
   }
@@ -239,7 +439,7 @@ impl Region {
   /// Since 1.10
   pub fn subtract(&mut self, region: &Region) -> super::Status {
     unsafe {
-      let foreign_result = cairo_region_subtract(self.opaque, region);
+      let foreign_result = std::mem::transmute(ffi::cairo_region_subtract(self.opaque, region.opaque));
       return foreign_result;
     }
   }
@@ -255,7 +455,7 @@ impl Region {
   /// Since 1.10
   pub fn subtract_rectangle(&mut self, rectangle: &Rectangle) -> super::Status {
     unsafe {
-      let foreign_result = cairo_region_subtract_rectangle(self.opaque, rectangle);
+      let foreign_result = std::mem::transmute(ffi::cairo_region_subtract_rectangle(self.opaque, rectangle as *Rectangle as *ffi::cairo_rectangle_int_t));
       return foreign_result;
     }
   }
@@ -271,7 +471,7 @@ impl Region {
   /// Since 1.10
   pub fn union(&mut self, region: &Region) -> super::Status {
     unsafe {
-      let foreign_result = cairo_region_union(self.opaque, region);
+      let foreign_result = std::mem::transmute(ffi::cairo_region_union(self.opaque, region.opaque));
       return foreign_result;
     }
   }
@@ -287,7 +487,7 @@ impl Region {
   /// Since 1.10
   pub fn union_rectangle(&mut self, rectangle: &Rectangle) -> super::Status {
     unsafe {
-      let foreign_result = cairo_region_union_rectangle(self.opaque, rectangle);
+      let foreign_result = std::mem::transmute(ffi::cairo_region_union_rectangle(self.opaque, rectangle as *Rectangle as *ffi::cairo_rectangle_int_t));
       return foreign_result;
     }
   }
@@ -303,7 +503,7 @@ impl Region {
   /// Since 1.10
   pub fn xor(&mut self, region: &Region) -> super::Status {
     unsafe {
-      let foreign_result = cairo_region_xor(self.opaque, region);
+      let foreign_result = std::mem::transmute(ffi::cairo_region_xor(self.opaque, region.opaque));
       return foreign_result;
     }
   }
@@ -319,70 +519,105 @@ impl Region {
   /// Since 1.10
   pub fn xor_rectangle(&mut self, rectangle: &Rectangle) -> super::Status {
     unsafe {
-      let foreign_result = cairo_region_xor_rectangle(self.opaque, rectangle);
+      let foreign_result = std::mem::transmute(ffi::cairo_region_xor_rectangle(self.opaque, rectangle as *Rectangle as *ffi::cairo_rectangle_int_t));
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_region_create() -> Region;
-  fn cairo_region_create_rectangle(rectangle: *Rectangle) -> Region;
-  fn cairo_region_create_rectangles(rectangles: *Rectangle, rectangles_length: i32) -> Region;
-  fn cairo_region_status(self_value: *mut libc::c_void) -> super::Status;
-  fn cairo_region_get_extents(self_value: *mut libc::c_void, extents: *mut Rectangle);
-  fn cairo_region_num_rectangles(self_value: *mut libc::c_void) -> i32;
-  fn cairo_region_get_rectangle(self_value: *mut libc::c_void, nth: i32, rectangle: *mut Rectangle);
-  fn cairo_region_is_empty(self_value: *mut libc::c_void) -> i32;
-  fn cairo_region_contains_point(self_value: *mut libc::c_void, x: i32, y: i32) -> i32;
-  fn cairo_region_contains_rectangle(self_value: *mut libc::c_void, rectangle: *Rectangle) -> overlap::Overlap;
-  fn cairo_region_equal(self_value: *mut libc::c_void, other: *libc::c_void) -> i32;
-  fn cairo_region_translate(self_value: *mut libc::c_void, dx: i32, dy: i32);
-  fn cairo_region_intersect_rectangle(self_value: *mut libc::c_void, rectangle: *Rectangle) -> super::Status;
-  fn cairo_region_subtract(self_value: *mut libc::c_void, region: *Region) -> super::Status;
-  fn cairo_region_subtract_rectangle(self_value: *mut libc::c_void, rectangle: *Rectangle) -> super::Status;
-  fn cairo_region_union(self_value: *mut libc::c_void, region: *Region) -> super::Status;
-  fn cairo_region_union_rectangle(self_value: *mut libc::c_void, rectangle: *Rectangle) -> super::Status;
-  fn cairo_region_xor(self_value: *mut libc::c_void, region: *Region) -> super::Status;
-  fn cairo_region_xor_rectangle(self_value: *mut libc::c_void, rectangle: *Rectangle) -> super::Status;
+
+/// Reads pixel x from a row of A1 image data. Pixels are packed into 32-bit words in native byte order, with the first pixel in the least significant bit on little-endian machines.
+#[cfg(target_endian = "little")]
+fn a1_pixel(row: &[u8], x: uint) -> bool {
+  row[x / 8] & (1 << (x % 8)) != 0
+}
+
+/// Reads pixel x from a row of A1 image data. Pixels are packed into 32-bit words in native byte order, with the first pixel in the most significant bit on big-endian machines.
+#[cfg(target_endian = "big")]
+fn a1_pixel(row: &[u8], x: uint) -> bool {
+  row[x / 8] & (0x80 >> (x % 8)) != 0
 }
 
 impl std::clone::Clone for Region {
   fn clone(&self) -> Region {
     unsafe {
-      let foreign_result = cairo_region_reference(self.opaque as *libc::c_void);
+      let foreign_result = Region { opaque: ffi::cairo_region_reference(self.opaque) };
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_region_reference(self_value: *libc::c_void) -> Region;
-}
 
 impl DeepClone for Region {
   fn deep_clone(&self) -> Region {
     unsafe {
-      let foreign_result = cairo_region_copy(self.opaque as *libc::c_void);
+      let foreign_result = Region { opaque: ffi::cairo_region_copy(self.opaque) };
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_region_copy(self_value: *libc::c_void) -> Region;
+
+impl std::cmp::PartialEq for Region {
+  fn eq(&self, other: &Region) -> bool {
+    unsafe {
+      let foreign_result = ffi::cairo_region_equal(self.opaque, other.opaque);
+      return foreign_result != 0;
+    }
+  }
+}
+
+impl std::ops::BitOr<Region, Region> for Region {
+  /// Returns a new region containing the union of both regions. Errors are reported by the status of the new region.
+  fn bitor(&self, other: &Region) -> Region {
+    let mut result = self.deep_clone();
+    result.union(other);
+    return result;
+  }
+}
+
+impl std::ops::BitAnd<Region, Region> for Region {
+  /// Returns a new region containing the intersection of both regions. Errors are reported by the status of the new region.
+  fn bitand(&self, other: &Region) -> Region {
+    let mut result = self.deep_clone();
+    result.intersect(other);
+    return result;
+  }
+}
+
+impl std::ops::Sub<Region, Region> for Region {
+  /// Returns a new region containing the area of self that is not in other. Errors are reported by the status of the new region.
+  fn sub(&self, other: &Region) -> Region {
+    let mut result = self.deep_clone();
+    result.subtract(other);
+    return result;
+  }
+}
+
+impl std::ops::BitXor<Region, Region> for Region {
+  /// Returns a new region containing the exclusive difference of both regions. Errors are reported by the status of the new region.
+  fn bitxor(&self, other: &Region) -> Region {
+    let mut result = self.deep_clone();
+    result.xor(other);
+    return result;
+  }
+}
+
+impl std::iter::FromIterator<Rectangle> for Region {
+  fn from_iter<T: Iterator<Rectangle>>(iterator: T) -> Region {
+    let rectangles: Vec<Rectangle> = iterator.collect();
+    return Region::rectangles(rectangles.as_slice());
+  }
 }
 
 impl std::ops::Drop for Region {
   fn drop(&mut self) {
     unsafe {
-      cairo_region_destroy(self.opaque);
+      ffi::cairo_region_destroy(self.opaque);
     }
   }
 }
 
-extern {
-  fn cairo_region_destroy(self_value: *mut libc::c_void);
-}
 
+pub mod damage;
 pub mod overlap;
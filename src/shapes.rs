@@ -0,0 +1,293 @@
+//! Add common shapes to the path of a cairo context
+
+use std::f64::consts::PI;
+use super::Cairo;
+
+/// shapes::Shapes adds path helpers for shapes that are not cairo primitives. Every shape except shapes::Shapes::polyline(), shapes::Shapes::quad_to() and shapes::Shapes::arc_to() starts a new sub-path and is closed, so it can be filled or stroked like cairo_rectangle().
+///
+/// Angles are in radians, measured from the positive X axis toward the positive Y axis, as for cairo_arc().
+pub trait Shapes {
+  /// Adds a closed sub-path for a rectangle with rounded corners. The radii are scaled down together when two radii of the same side add up to more than its length, as CSS does for border-radius.
+  ///
+  /// x : the X coordinate of the top left corner of the rectangle
+  ///
+  /// y : the Y coordinate of the top left corner of the rectangle
+  ///
+  /// width : the width of the rectangle
+  ///
+  /// height : the height of the rectangle
+  ///
+  /// radii : the radii of the top left, top right, bottom right and bottom left corners
+  fn rounded_rectangle(&mut self, x: f64, y: f64, width: f64, height: f64, radii: [f64, ..4]);
+
+  /// Adds a closed sub-path for an axis-aligned ellipse. Nothing is added unless both radii are positive.
+  ///
+  /// xc : X position of the center of the ellipse
+  ///
+  /// yc : Y position of the center of the ellipse
+  ///
+  /// radius_x : the horizontal radius
+  ///
+  /// radius_y : the vertical radius
+  fn ellipse(&mut self, xc: f64, yc: f64, radius_x: f64, radius_y: f64);
+
+  /// Adds a closed sub-path for a regular polygon. Nothing is added for less than 3 sides.
+  ///
+  /// xc : X position of the center of the polygon
+  ///
+  /// yc : Y position of the center of the polygon
+  ///
+  /// radius : the distance from the center to the vertices
+  ///
+  /// sides : the number of sides
+  ///
+  /// angle : the angle of the first vertex
+  fn regular_polygon(&mut self, xc: f64, yc: f64, radius: f64, sides: uint, angle: f64);
+
+  /// Adds a closed sub-path for a star, alternating between outer and inner vertices. Nothing is added for less than 2 points.
+  ///
+  /// xc : X position of the center of the star
+  ///
+  /// yc : Y position of the center of the star
+  ///
+  /// outer_radius : the distance from the center to the tips of the points
+  ///
+  /// inner_radius : the distance from the center to the vertices between the points
+  ///
+  /// points : the number of points
+  ///
+  /// angle : the angle of the first point
+  fn star(&mut self, xc: f64, yc: f64, outer_radius: f64, inner_radius: f64, points: uint, angle: f64);
+
+  /// Adds an open sub-path through points, starting with cairo_move_to() at the first point. Nothing is added for an empty slice.
+  ///
+  /// points : the (x, y) positions of the vertices
+  fn polyline(&mut self, points: &[(f64, f64)]);
+
+  /// Adds a quadratic Bézier spline from the current point to (x2, y2), using (x1, y1) as the control point. The spline is added as the equivalent cubic spline with cairo_curve_to(). If there is no current point, the path starts at (x1, y1).
+  ///
+  /// x1 : the X coordinate of the control point
+  ///
+  /// y1 : the Y coordinate of the control point
+  ///
+  /// x2 : the X coordinate of the end of the spline
+  ///
+  /// y2 : the Y coordinate of the end of the spline
+  fn quad_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64);
+
+  /// Adds an arc of the given radius that is tangent to the line from the current point to (x1, y1) and to the line from (x1, y1) to (x2, y2), connected to the current point by a straight line, like arcTo() of the HTML canvas. The arc ends at its tangent point on the second line, not at (x2, y2).
+  ///
+  /// A straight line to (x1, y1) is added instead when the radius is zero or the points lie on a line. If there is no current point, the path starts at (x1, y1).
+  ///
+  /// x1 : the X coordinate of the corner
+  ///
+  /// y1 : the Y coordinate of the corner
+  ///
+  /// x2 : the X coordinate of a point on the second line
+  ///
+  /// y2 : the Y coordinate of a point on the second line
+  ///
+  /// radius : the radius of the arc
+  fn arc_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64);
+}
+
+impl Shapes for Cairo {
+  fn rounded_rectangle(&mut self, x: f64, y: f64, width: f64, height: f64, radii: [f64, ..4]) {
+    let (top_left, top_right, bottom_right, bottom_left) = (radii[0], radii[1], radii[2], radii[3]);
+    let mut factor = 1.0f64;
+    for &(length, sum) in [(width, top_left + top_right), (height, top_right + bottom_right), (width, bottom_right + bottom_left), (height, bottom_left + top_left)].iter() {
+      if sum > length {
+        factor = factor.min(length / sum);
+      }
+    }
+    let corners = [
+      (x + width - top_right * factor, y + top_right * factor, top_right * factor, -PI / 2.0),
+      (x + width - bottom_right * factor, y + height - bottom_right * factor, bottom_right * factor, 0.0),
+      (x + bottom_left * factor, y + height - bottom_left * factor, bottom_left * factor, PI / 2.0),
+      (x + top_left * factor, y + top_left * factor, top_left * factor, PI)
+    ];
+    self.new_sub_path();
+    self.move_to(x + top_left * factor, y);
+    for &(xc, yc, radius, angle) in corners.iter() {
+      if radius > 0.0 {
+        self.arc(xc, yc, radius, angle, angle + PI / 2.0);
+      } else {
+        self.line_to(xc, yc);
+      }
+    }
+    self.close_path();
+  }
+
+  fn ellipse(&mut self, xc: f64, yc: f64, radius_x: f64, radius_y: f64) {
+    if radius_x <= 0.0 || radius_y <= 0.0 {
+      return;
+    }
+    self.save();
+    self.translate(xc, yc);
+    self.scale(radius_x, radius_y);
+    self.new_sub_path();
+    self.arc(0.0, 0.0, 1.0, 0.0, 2.0 * PI);
+    self.close_path();
+    self.restore();
+  }
+
+  fn regular_polygon(&mut self, xc: f64, yc: f64, radius: f64, sides: uint, angle: f64) {
+    if sides < 3 {
+      return;
+    }
+    self.new_sub_path();
+    for i in range(0, sides) {
+      let vertex_angle = angle + 2.0 * PI * i as f64 / sides as f64;
+      self.line_to(xc + radius * vertex_angle.cos(), yc + radius * vertex_angle.sin());
+    }
+    self.close_path();
+  }
+
+  fn star(&mut self, xc: f64, yc: f64, outer_radius: f64, inner_radius: f64, points: uint, angle: f64) {
+    if points < 2 {
+      return;
+    }
+    self.new_sub_path();
+    for i in range(0, 2 * points) {
+      let radius = if i % 2 == 0 { outer_radius } else { inner_radius };
+      let vertex_angle = angle + PI * i as f64 / points as f64;
+      self.line_to(xc + radius * vertex_angle.cos(), yc + radius * vertex_angle.sin());
+    }
+    self.close_path();
+  }
+
+  fn polyline(&mut self, points: &[(f64, f64)]) {
+    for (i, &(x, y)) in points.iter().enumerate() {
+      if i == 0 {
+        self.move_to(x, y);
+      } else {
+        self.line_to(x, y);
+      }
+    }
+  }
+
+  fn quad_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
+    if !self.has_current_point() {
+      self.move_to(x1, y1);
+    }
+    let (x0, y0) = self.get_current_point();
+    self.curve_to(x0 + 2.0 / 3.0 * (x1 - x0), y0 + 2.0 / 3.0 * (y1 - y0),
+                  x2 + 2.0 / 3.0 * (x1 - x2), y2 + 2.0 / 3.0 * (y1 - y2),
+                  x2, y2);
+  }
+
+  fn arc_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64) {
+    if !self.has_current_point() {
+      self.move_to(x1, y1);
+    }
+    let (x0, y0) = self.get_current_point();
+    let (dx0, dy0) = (x0 - x1, y0 - y1);
+    let (dx2, dy2) = (x2 - x1, y2 - y1);
+    let length0 = (dx0 * dx0 + dy0 * dy0).sqrt();
+    let length2 = (dx2 * dx2 + dy2 * dy2).sqrt();
+    if radius <= 0.0 || length0 == 0.0 || length2 == 0.0 {
+      self.line_to(x1, y1);
+      return;
+    }
+    // Unit vectors from the corner toward the current point and toward (x2, y2).
+    let (ux0, uy0) = (dx0 / length0, dy0 / length0);
+    let (ux2, uy2) = (dx2 / length2, dy2 / length2);
+    let cross = ux0 * uy2 - uy0 * ux2;
+    if cross.abs() < 1e-9 {
+      self.line_to(x1, y1);
+      return;
+    }
+    let half_angle = (ux0 * ux2 + uy0 * uy2).max(-1.0).min(1.0).acos() / 2.0;
+    let tangent_distance = radius / half_angle.tan();
+    let center_distance = radius / half_angle.sin();
+    let (bx, by) = (ux0 + ux2, uy0 + uy2);
+    let bisector_length = (bx * bx + by * by).sqrt();
+    let (xc, yc) = (x1 + bx / bisector_length * center_distance, y1 + by / bisector_length * center_distance);
+    let (tx0, ty0) = (x1 + ux0 * tangent_distance, y1 + uy0 * tangent_distance);
+    let (tx2, ty2) = (x1 + ux2 * tangent_distance, y1 + uy2 * tangent_distance);
+    let angle1 = (ty0 - yc).atan2(tx0 - xc);
+    let angle2 = (ty2 - yc).atan2(tx2 - xc);
+    self.line_to(tx0, ty0);
+    if cross < 0.0 {
+      self.arc(xc, yc, radius, angle1, angle2);
+    } else {
+      self.arc_negative(xc, yc, radius, angle1, angle2);
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::Shapes;
+  use super::super::Cairo;
+  use super::super::surface::Surface;
+  use super::super::surface::format;
+
+  fn context() -> Cairo {
+    let mut surface = Surface::image(format::ARGB32, 200, 200);
+    Cairo::new(&mut surface)
+  }
+
+  fn close(a: (f64, f64), b: (f64, f64)) -> bool {
+    let ((ax, ay), (bx, by)) = (a, b);
+    (ax - bx).abs() < 0.01 && (ay - by).abs() < 0.01
+  }
+
+  #[test]
+  fn rounded_rectangle_scales_large_radii() {
+    let mut cr = context();
+    cr.rounded_rectangle(10.0, 20.0, 100.0, 50.0, [100.0, 0.0, 0.0, 0.0]);
+    let (x1, y1, x2, y2) = cr.path_extents();
+    assert!(close((x1, y1), (10.0, 20.0)));
+    assert!(close((x2, y2), (110.0, 70.0)));
+  }
+
+  #[test]
+  fn regular_polygon() {
+    let mut cr = context();
+    cr.regular_polygon(50.0, 50.0, 10.0, 4, 0.0);
+    let (x1, y1, x2, y2) = cr.path_extents();
+    assert!(close((x1, y1), (40.0, 40.0)));
+    assert!(close((x2, y2), (60.0, 60.0)));
+    assert!(close(cr.get_current_point(), (60.0, 50.0)));
+  }
+
+  #[test]
+  fn star_starts_at_an_outer_point() {
+    let mut cr = context();
+    cr.star(50.0, 50.0, 20.0, 8.0, 5, 0.0);
+    assert!(close(cr.get_current_point(), (70.0, 50.0)));
+  }
+
+  #[test]
+  fn degenerate_shapes_add_nothing() {
+    let mut cr = context();
+    cr.regular_polygon(50.0, 50.0, 10.0, 2, 0.0);
+    cr.star(50.0, 50.0, 20.0, 8.0, 1, 0.0);
+    cr.ellipse(50.0, 50.0, -1.0, 10.0);
+    cr.polyline(&[]);
+    assert!(!cr.has_current_point());
+  }
+
+  #[test]
+  fn polyline_and_quad_to() {
+    let mut cr = context();
+    cr.polyline(&[(1.0, 2.0), (3.0, 4.0)]);
+    assert!(close(cr.get_current_point(), (3.0, 4.0)));
+    cr.new_path();
+    cr.quad_to(1.0, 1.0, 4.0, 0.0);
+    assert!(close(cr.get_current_point(), (4.0, 0.0)));
+  }
+
+  #[test]
+  fn arc_to_ends_at_the_second_tangent_point() {
+    let mut cr = context();
+    cr.move_to(0.0, 0.0);
+    cr.arc_to(10.0, 0.0, 10.0, 10.0, 2.0);
+    assert!(close(cr.get_current_point(), (10.0, 2.0)));
+    cr.new_path();
+    cr.move_to(0.0, 0.0);
+    cr.arc_to(5.0, 0.0, 10.0, 0.0, 2.0);
+    assert!(close(cr.get_current_point(), (5.0, 0.0)));
+  }
+}
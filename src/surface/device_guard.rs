@@ -0,0 +1,51 @@
+//! Hold a device acquired for the current thread
+
+use std;
+use super::Device;
+
+/// A surface::device_guard::DeviceGuard holds a surface::Device acquired with cairo_device_acquire(), see surface::Device::lock(). The device is released with cairo_device_release() when the guard goes out of scope.
+///
+/// While the guard exists no other thread can acquire the device. As for cairo_device_acquire(), cairo functions which may acquire devices must not be called while the guard exists.
+///
+/// The guard dereferences to the device, while cairo_device_acquire() and cairo_device_release() themselves are not public, so a device can only be released by dropping its guard.
+///
+/// Since 1.10
+pub struct DeviceGuard<'a> {
+  device: &'a mut Device,
+  // A reference of its own, which is released even if the borrowed device is replaced through the guard.
+  acquired: Device
+}
+
+impl<'a> DeviceGuard<'a> {
+  /// Acquires device for the current thread. This function will block until no other thread has acquired the device.
+  ///
+  /// device : a surface::Device
+  ///
+  /// Returns : a guard holding the device, or the error status if the device is in an error state and could not be acquired.
+  pub fn acquire(device: &'a mut Device) -> Result<DeviceGuard<'a>, super::super::Status> {
+    let mut acquired = device.clone();
+    match acquired.acquire() {
+      super::super::Success => Ok(DeviceGuard { device: device, acquired: acquired }),
+      status => Err(status)
+    }
+  }
+}
+
+impl<'a> std::ops::Deref<Device> for DeviceGuard<'a> {
+  fn deref<'b>(&'b self) -> &'b Device {
+    &*self.device
+  }
+}
+
+impl<'a> std::ops::DerefMut<Device> for DeviceGuard<'a> {
+  fn deref_mut<'b>(&'b mut self) -> &'b mut Device {
+    &mut *self.device
+  }
+}
+
+#[unsafe_destructor]
+impl<'a> std::ops::Drop for DeviceGuard<'a> {
+  fn drop(&mut self) {
+    self.acquired.release();
+  }
+}
@@ -0,0 +1,25 @@
+//! Name the MIME types of data attached to surfaces
+
+/// JPEG image data, since 1.10
+pub static JPEG: &'static str = "image/jpeg";
+
+/// PNG image data, since 1.10
+pub static PNG: &'static str = "image/png";
+
+/// JPEG 2000 image data, since 1.10
+pub static JP2: &'static str = "image/jp2";
+
+/// URI of the image, emitted as is by the SVG backend, since 1.10
+pub static URI: &'static str = "text/x-uri";
+
+/// Unique identifier of the image, so backends can share its data between surfaces, since 1.12
+pub static UNIQUE_ID: &'static str = "unique-id";
+
+/// JBIG2 image data of an embedded stream, since 1.14
+pub static JBIG2: &'static str = "application/x-cairo.jbig2";
+
+/// JBIG2 global segment shared by several JBIG2 images, since 1.14
+pub static JBIG2_GLOBAL: &'static str = "application/x-cairo.jbig2-global";
+
+/// Identifier linking JBIG2 images to their global segment, since 1.14
+pub static JBIG2_GLOBAL_ID: &'static str = "application/x-cairo.jbig2-global-id";
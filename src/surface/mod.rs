@@ -2,6 +2,7 @@
 
 use std;
 use libc;
+use ffi;
 
 /// surface::SVGVersion is used to describe the version number of the SVG specification that a generated SVG file will conform to.
 ///
@@ -73,7 +74,7 @@ impl Device {
   /// Since 1.10
   pub fn status(&mut self) -> super::Status {
     unsafe {
-      let foreign_result = cairo_device_status(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_device_status(self.opaque));
       return foreign_result;
     }
   }
@@ -89,7 +90,7 @@ impl Device {
   /// Since 1.10
   pub fn finish(&mut self) {
     unsafe {
-      cairo_device_finish(self.opaque);
+      ffi::cairo_device_finish(self.opaque);
     }
   }
 
@@ -102,7 +103,7 @@ impl Device {
   /// Since 1.10
   pub fn flush(&mut self) {
     unsafe {
-      cairo_device_flush(self.opaque);
+      ffi::cairo_device_flush(self.opaque);
     }
   }
 
@@ -115,7 +116,7 @@ impl Device {
   /// Since 1.10
   pub fn get_type(&mut self) -> device_type::DeviceType {
     unsafe {
-      let foreign_result = cairo_device_get_type(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_device_get_type(self.opaque));
       return foreign_result;
     }
   }
@@ -129,7 +130,7 @@ impl Device {
   /// Since 1.10
   pub fn reference_count(&mut self) -> i32 {
     unsafe {
-      let foreign_result = cairo_device_get_reference_count(self.opaque);
+      let foreign_result = ffi::cairo_device_get_reference_count(self.opaque) as i32;
       return foreign_result;
     }
   }
@@ -149,9 +150,9 @@ impl Device {
   /// Returns : CAIRO_STATUS_SUCCESS on success or an error code if the device is in an error state and could not be acquired. After a successful call to cairo_device_acquire(), a matching call to cairo_device_release() is required.
   /// 
   /// Since 1.10
-  pub fn acquire(&mut self) -> super::Status {
+  fn acquire(&mut self) -> super::Status {
     unsafe {
-      let foreign_result = cairo_device_acquire(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_device_acquire(self.opaque));
       return foreign_result;
     }
   }
@@ -161,47 +162,179 @@ impl Device {
   /// device : a surface::Device
   /// 
   /// Since 1.10
-  pub fn release(&mut self) {
+  fn release(&mut self) {
     unsafe {
-      cairo_device_release(self.opaque);
+      ffi::cairo_device_release(self.opaque);
+    }
+  }
+
+  /// Acquires the device for the current thread, see cairo_device_acquire(). The device is released when the returned guard goes out of scope, so it can not be left acquired by accident.
+  ///
+  /// device : a surface::Device
+  ///
+  /// Returns : a guard holding the device, or the error status if the device is in an error state and could not be acquired.
+  ///
+  /// Since 1.10
+  pub fn lock<'a>(&'a mut self) -> Result<device_guard::DeviceGuard<'a>, super::Status> {
+    device_guard::DeviceGuard::acquire(self)
+  }
+
+  /// Converts the device into a typed device, such as surface::typed_device::ScriptDevice, if it has the matching surface::device_type::DeviceType.
+  ///
+  /// device : a surface::Device
+  ///
+  /// Returns : the typed device, or the device itself if it is of another type.
+  pub fn downcast<T: typed_device::TypedDevice>(self) -> Result<T, Device> {
+    typed_device::TypedDevice::from_device(self)
+  }
+
+  /// Returns the total time spent in operations observed through observer surfaces created for surfaces of device.
+  ///
+  /// device : a surface::Device
+  ///
+  /// Returns : the elapsed time in nanoseconds
+  ///
+  /// Since 1.12
+  pub fn observer_elapsed(&mut self) -> f64 {
+    unsafe {
+      let foreign_result = ffi::cairo_device_observer_elapsed(self.opaque);
+      return foreign_result;
+    }
+  }
+
+  /// Returns the time spent in paint operations observed through observer surfaces created for surfaces of device.
+  ///
+  /// device : a surface::Device
+  ///
+  /// Returns : the elapsed time in nanoseconds
+  ///
+  /// Since 1.12
+  pub fn observer_paint_elapsed(&mut self) -> f64 {
+    unsafe {
+      let foreign_result = ffi::cairo_device_observer_paint_elapsed(self.opaque);
+      return foreign_result;
     }
   }
-}
 
-extern {
-  fn cairo_device_status(self_value: *mut libc::c_void) -> super::Status;
-  fn cairo_device_finish(self_value: *mut libc::c_void);
-  fn cairo_device_flush(self_value: *mut libc::c_void);
-  fn cairo_device_get_type(self_value: *mut libc::c_void) -> device_type::DeviceType;
-  fn cairo_device_get_reference_count(self_value: *mut libc::c_void) -> i32;
-  fn cairo_device_acquire(self_value: *mut libc::c_void) -> super::Status;
-  fn cairo_device_release(self_value: *mut libc::c_void);
+  /// Returns the time spent in mask operations observed through observer surfaces created for surfaces of device.
+  ///
+  /// device : a surface::Device
+  ///
+  /// Returns : the elapsed time in nanoseconds
+  ///
+  /// Since 1.12
+  pub fn observer_mask_elapsed(&mut self) -> f64 {
+    unsafe {
+      let foreign_result = ffi::cairo_device_observer_mask_elapsed(self.opaque);
+      return foreign_result;
+    }
+  }
+
+  /// Returns the time spent in fill operations observed through observer surfaces created for surfaces of device.
+  ///
+  /// device : a surface::Device
+  ///
+  /// Returns : the elapsed time in nanoseconds
+  ///
+  /// Since 1.12
+  pub fn observer_fill_elapsed(&mut self) -> f64 {
+    unsafe {
+      let foreign_result = ffi::cairo_device_observer_fill_elapsed(self.opaque);
+      return foreign_result;
+    }
+  }
+
+  /// Returns the time spent in stroke operations observed through observer surfaces created for surfaces of device.
+  ///
+  /// device : a surface::Device
+  ///
+  /// Returns : the elapsed time in nanoseconds
+  ///
+  /// Since 1.12
+  pub fn observer_stroke_elapsed(&mut self) -> f64 {
+    unsafe {
+      let foreign_result = ffi::cairo_device_observer_stroke_elapsed(self.opaque);
+      return foreign_result;
+    }
+  }
+
+  /// Returns the time spent in glyphs operations observed through observer surfaces created for surfaces of device.
+  ///
+  /// device : a surface::Device
+  ///
+  /// Returns : the elapsed time in nanoseconds
+  ///
+  /// Since 1.12
+  pub fn observer_glyphs_elapsed(&mut self) -> f64 {
+    unsafe {
+      let foreign_result = ffi::cairo_device_observer_glyphs_elapsed(self.opaque);
+      return foreign_result;
+    }
+  }
+
+  /// Returns a report of the operations observed for device and the time spent in them.
+  ///
+  /// device : a surface::Device
+  ///
+  /// Returns : the report, or the error status reported by cairo while printing it.
+  ///
+  /// Since 1.12
+  pub fn observer_print(&mut self) -> Result<String, super::error::Error> {
+    unsafe {
+      let mut stream = std::io::MemWriter::new();
+      let closure = &mut stream as *mut std::io::MemWriter as *mut libc::c_void;
+      let status: super::Status = std::mem::transmute(ffi::cairo_device_observer_print(self.opaque, write_stream::<std::io::MemWriter>, closure));
+      match status {
+        super::Success => return Ok(String::from_utf8_lossy(stream.unwrap().as_slice()).into_string()),
+        error => return Err(super::error::CairoError(error))
+      }
+    }
+  }
+
+  /// Creates a output device for emitting the script, see surface::typed_device::ScriptDevice::new().
+  ///
+  /// filename : the name (path) of the file to write the script to
+  ///
+  /// Returns : the newly created script device.
+  ///
+  /// Since 1.12
+  #[cfg(feature = "script")]
+  pub fn script(filename: &str) -> typed_device::ScriptDevice {
+    typed_device::ScriptDevice::new(filename)
+  }
+
+  /// Creates a output device for emitting the script to stream, see surface::typed_device::ScriptDevice::for_stream().
+  ///
+  /// stream : the writer receiving the script
+  ///
+  /// Returns : the newly created script device, or the error status of the device if cairo failed to create it.
+  ///
+  /// Since 1.12
+  #[cfg(feature = "script")]
+  pub fn script_for_stream<W: std::io::Writer + 'static>(stream: W) -> Result<typed_device::ScriptDevice, super::error::Error> {
+    typed_device::ScriptDevice::for_stream(stream)
+  }
 }
 
+
 impl std::clone::Clone for Device {
   fn clone(&self) -> Device {
     unsafe {
-      let foreign_result = cairo_device_reference(self.opaque as *libc::c_void);
+      let foreign_result = Device { opaque: ffi::cairo_device_reference(self.opaque) };
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_device_reference(self_value: *libc::c_void) -> Device;
-}
 
 impl std::ops::Drop for Device {
   fn drop(&mut self) {
     unsafe {
-      cairo_device_destroy(self.opaque);
+      ffi::cairo_device_destroy(self.opaque);
     }
   }
 }
 
-extern {
-  fn cairo_device_destroy(self_value: *mut libc::c_void);
-}
 
 impl Surface {
   /// Create a new image surface that is as compatible as possible for uploading to and the use in conjunction with an existing surface. However, this surface can still be used like any normal image surface.
@@ -222,9 +355,9 @@ impl Surface {
   /// a pointer to the newly allocated image surface. The caller owns the surface and should call cairo_surface_destroy() when done with it. This function always returns a valid pointer, but it will return a pointer to a "nil" surface if other is already in an error state or any other error occurs.
   /// 
   /// Since 1.12
-  pub fn similar_image(format: format::Format, width: i32, height: i32) -> Surface {
+  pub fn similar_image(other: &mut Surface, format: format::Format, width: i32, height: i32) -> Surface {
     unsafe {
-      let foreign_result = cairo_surface_create_similar_image(format, width, height);
+      let foreign_result = Surface { opaque: ffi::cairo_surface_create_similar_image(other.opaque, format as ffi::cairo_format_t, width, height) };
       return foreign_result;
     }
   }
@@ -249,9 +382,9 @@ impl Surface {
   /// a pointer to the newly allocated surface. The caller owns the surface and should call cairo_surface_destroy() when done with it. This function always returns a valid pointer, but it will return a pointer to a "nil" surface if other is already in an error state or any other error occurs.
   /// 
   /// Since 1.10
-  pub fn for_rectangle(x: f64, y: f64, width: f64, height: f64) -> Surface {
+  pub fn for_rectangle(target: &mut Surface, x: f64, y: f64, width: f64, height: f64) -> Surface {
     unsafe {
-      let foreign_result = cairo_surface_create_for_rectangle(x, y, width, height);
+      let foreign_result = Surface { opaque: ffi::cairo_surface_create_for_rectangle(target.opaque, x, y, width, height) };
       return foreign_result;
     }
   }
@@ -265,7 +398,7 @@ impl Surface {
   /// Since 1.0
   pub fn status(&mut self) -> super::Status {
     unsafe {
-      let foreign_result = cairo_surface_status(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_surface_status(self.opaque));
       return foreign_result;
     }
   }
@@ -279,7 +412,7 @@ impl Surface {
   /// Since 1.0
   pub fn finish(&mut self) {
     unsafe {
-      cairo_surface_finish(self.opaque);
+      ffi::cairo_surface_finish(self.opaque);
     }
   }
 
@@ -290,7 +423,7 @@ impl Surface {
   /// Since 1.0
   pub fn flush(&mut self) {
     unsafe {
-      cairo_surface_flush(self.opaque);
+      ffi::cairo_surface_flush(self.opaque);
     }
   }
 
@@ -298,13 +431,17 @@ impl Surface {
   /// 
   /// surface : a surface::Surface
   ///
-  /// Returns : The device for surface or NULL if the surface does not have an associated device.
+  /// Returns : The device for surface, referenced for the caller, or None if the surface does not have an associated device.
   ///
   /// Since 1.10
-  pub fn get_device(&mut self) -> Device {
+  pub fn get_device(&mut self) -> Option<Device> {
     unsafe {
-      let foreign_result = cairo_surface_get_device(self.opaque);
-      return foreign_result.clone();
+      let device = ffi::cairo_surface_get_device(self.opaque);
+      if device.is_null() {
+        return None;
+      }
+      let foreign_result = Device { opaque: ffi::cairo_device_reference(device) };
+      return Some(foreign_result);
     }
   }
 
@@ -317,7 +454,7 @@ impl Surface {
   /// Since 1.0
   pub fn get_font_options(&mut self, options: &mut super::font::Options) {
     unsafe {
-      cairo_surface_get_font_options(self.opaque, options.opaque);
+      ffi::cairo_surface_get_font_options(self.opaque, options.opaque);
     }
   }
 
@@ -330,7 +467,7 @@ impl Surface {
   /// Since 1.2
   pub fn get_content(&mut self) -> content::Content {
     unsafe {
-      let foreign_result = cairo_surface_get_content(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_surface_get_content(self.opaque));
       return foreign_result;
     }
   }
@@ -342,7 +479,7 @@ impl Surface {
   /// Since 1.0
   pub fn mark_dirty(&mut self) {
     unsafe {
-      cairo_surface_mark_dirty(self.opaque);
+      ffi::cairo_surface_mark_dirty(self.opaque);
     }
   }
 
@@ -361,9 +498,9 @@ impl Surface {
   /// height : height of dirty rectangle
   ///
   /// Since 1.0
-  pub fn mark_dirty_rectangle(&mut self, x: f64, y: f64, width: f64, height: f64) {
+  pub fn mark_dirty_rectangle(&mut self, x: i32, y: i32, width: i32, height: i32) {
     unsafe {
-      cairo_surface_mark_dirty_rectangle(self.opaque, x, y, width, height);
+      ffi::cairo_surface_mark_dirty_rectangle(self.opaque, x, y, width, height);
     }
   }
 
@@ -380,7 +517,7 @@ impl Surface {
   /// Since 1.0
   pub fn set_device_offset(&mut self, x_offset: f64, y_offset: f64) {
     unsafe {
-      cairo_surface_set_device_offset(self.opaque, x_offset, y_offset);
+      ffi::cairo_surface_set_device_offset(self.opaque, x_offset, y_offset);
     }
   }
 
@@ -397,11 +534,135 @@ impl Surface {
     unsafe {
       let mut x_offset:f64 = std::intrinsics::init();
       let mut y_offset:f64 = std::intrinsics::init();
-      cairo_surface_get_device_offset(self.opaque, &mut x_offset, &mut y_offset);
+      ffi::cairo_surface_get_device_offset(self.opaque, &mut x_offset, &mut y_offset);
       return (x_offset, y_offset);
     }
   }
 
+  /// Sets a scale that is multiplied to the device coordinates determined by the CTM when drawing to surface. One common use for this is to render to very high resolution display devices at a scale factor, so that code that assumes 1 pixel will be a certain size will still work. Setting a transformation via cairo_scale() isn't sufficient to do this, since functions like cairo_device_to_user() will expose the hidden scale.
+  ///
+  /// Note that the scale affects drawing to the surface as well as using the surface in a source pattern.
+  ///
+  /// surface : a surface::Surface
+  ///
+  /// x_scale : a scale factor in the X direction
+  ///
+  /// y_scale : a scale factor in the Y direction
+  ///
+  /// Since 1.14
+  #[cfg(feature = "v1_14")]
+  pub fn set_device_scale(&mut self, x_scale: f64, y_scale: f64) {
+    unsafe {
+      ffi::cairo_surface_set_device_scale(self.opaque, x_scale, y_scale);
+    }
+  }
+
+  /// This function returns the previous device scale set by cairo_surface_set_device_scale().
+  ///
+  /// surface : a surface::Surface
+  ///
+  /// Returns : the scale in the X and Y direction, in device units
+  ///
+  /// Since 1.14
+  #[cfg(feature = "v1_14")]
+  pub fn get_device_scale(&mut self) -> (f64, f64) {
+    unsafe {
+      let mut x_scale:f64 = std::intrinsics::init();
+      let mut y_scale:f64 = std::intrinsics::init();
+      ffi::cairo_surface_get_device_scale(self.opaque, &mut x_scale, &mut y_scale);
+      return (x_scale, y_scale);
+    }
+  }
+
+  /// Attach an image in the format mime_type to surface. To remove the data from a surface, call surface::Surface::remove_mime_data() with the same mime type.
+  ///
+  /// The attached image (or filename) data can later be used by backends which support it (currently: PDF, PS, SVG and Win32 Printing surfaces) to emit this data instead of making a snapshot of the surface. This approach tends to be faster and requires less memory and disk space.
+  ///
+  /// The recognized MIME types are listed in surface::mime, such as surface::mime::JPEG. See the documentation of the backends for the MIME types they accept.
+  ///
+  /// The data is owned by the surface, which drops it when it is replaced or removed, or the surface is destroyed.
+  ///
+  /// surface : a surface::Surface
+  ///
+  /// mime_type : the MIME type of the image data
+  ///
+  /// data : the image data to attach to the surface
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or CAIRO_STATUS_NO_MEMORY if a slot could not be allocated for the user data.
+  ///
+  /// Since 1.10
+  pub fn set_mime_data(&mut self, mime_type: &str, data: Vec<u8>) -> super::Status {
+    unsafe {
+      use std::c_str::ToCStr;
+      let bytes = data.as_ptr();
+      let length = data.len() as libc::c_ulong;
+      let closure: *mut libc::c_void = std::mem::transmute(box data);
+      let status: super::Status = std::mem::transmute(ffi::cairo_surface_set_mime_data(self.opaque, mime_type.to_c_str().unwrap(), bytes, length, destroy_mime_data, closure));
+      match status {
+        super::Success => {},
+        // Cairo only takes the data on success, otherwise it is dropped here.
+        _ => destroy_mime_data(closure)
+      }
+      return status;
+    }
+  }
+
+  /// Detach the image data attached to surface for mime_type, see surface::Surface::set_mime_data().
+  ///
+  /// surface : a surface::Surface
+  ///
+  /// mime_type : the MIME type of the image data
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or the error status of surface.
+  ///
+  /// Since 1.10
+  pub fn remove_mime_data(&mut self, mime_type: &str) -> super::Status {
+    unsafe {
+      use std::c_str::ToCStr;
+      let foreign_result = std::mem::transmute(ffi::cairo_surface_set_mime_data(self.opaque, mime_type.to_c_str().unwrap(), std::ptr::null(), 0, destroy_mime_data, std::ptr::mut_null()));
+      return foreign_result;
+    }
+  }
+
+  /// Return mime data previously attached to surface using the specified mime type.
+  ///
+  /// surface : a surface::Surface
+  ///
+  /// mime_type : the mime type of the image data
+  ///
+  /// Returns : a copy of the image data, or None if no data is attached for mime_type.
+  ///
+  /// Since 1.10
+  pub fn get_mime_data(&mut self, mime_type: &str) -> Option<Vec<u8>> {
+    unsafe {
+      use std::c_str::ToCStr;
+      let mut data: *u8 = std::ptr::null();
+      let mut length: libc::c_ulong = 0;
+      ffi::cairo_surface_get_mime_data(self.opaque, mime_type.to_c_str().unwrap(), &mut data, &mut length);
+      if data.is_null() {
+        return None;
+      }
+      return Some(std::vec::raw::from_buf(data, length as uint));
+    }
+  }
+
+  /// Return whether surface supports mime_type.
+  ///
+  /// surface : a surface::Surface
+  ///
+  /// mime_type : the mime type
+  ///
+  /// Returns : true if surface supports mime_type, false otherwise.
+  ///
+  /// Since 1.12
+  pub fn supports_mime_type(&mut self, mime_type: &str) -> bool {
+    unsafe {
+      use std::c_str::ToCStr;
+      let foreign_result = ffi::cairo_surface_supports_mime_type(self.opaque, mime_type.to_c_str().unwrap());
+      return foreign_result != 0;
+    }
+  }
+
   /// Set the horizontal and vertical resolution for image fallbacks.
   /// 
   /// When certain operations aren't supported natively by a backend, cairo will fallback by rendering operations to an image and then overlaying that image onto the output. For backends that are natively vector-oriented, this function can be used to set the resolution used for these image fallbacks, (larger values will result in more detailed images, but also larger file sizes).
@@ -423,7 +684,7 @@ impl Surface {
   /// Since 1.2
   pub fn surface_set_fallback_resolution(&mut self, x_pixels_per_inch: f64, y_pixels_per_inch: f64) {
     unsafe {
-      cairo_surface_set_fallback_resolution(self.opaque, x_pixels_per_inch, y_pixels_per_inch);
+      ffi::cairo_surface_set_fallback_resolution(self.opaque, x_pixels_per_inch, y_pixels_per_inch);
     }
   }
 
@@ -440,7 +701,7 @@ impl Surface {
     unsafe {
       let mut x_pixels_per_inch:f64 = std::intrinsics::init();
       let mut y_pixels_per_inch:f64 = std::intrinsics::init();
-      cairo_surface_get_fallback_resolution(self.opaque, &mut x_pixels_per_inch, &mut y_pixels_per_inch);
+      ffi::cairo_surface_get_fallback_resolution(self.opaque, &mut x_pixels_per_inch, &mut y_pixels_per_inch);
       return (x_pixels_per_inch, y_pixels_per_inch);
     }
   }
@@ -454,7 +715,7 @@ impl Surface {
   /// Since 1.2
   pub fn get_type(&mut self) -> surface_type::SurfaceType {
     unsafe {
-      let foreign_result = cairo_surface_get_type(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_surface_get_type(self.opaque));
       return foreign_result;
     }
   }
@@ -468,7 +729,57 @@ impl Surface {
   /// Since 1.4
   pub fn get_reference_count(&mut self) -> i32 {
     unsafe {
-      let foreign_result = cairo_surface_get_reference_count(self.opaque);
+      let foreign_result = ffi::cairo_surface_get_reference_count(self.opaque) as i32;
+      return foreign_result;
+    }
+  }
+
+  /// Attach user data to surface. To remove user data from surface, call surface::Surface::remove_user_data() with the same key. The data is dropped when it is replaced or removed, or when the last reference to surface is destroyed.
+  ///
+  /// surface : a surface::Surface
+  ///
+  /// key : the address of a user_data::Key to attach the user data to
+  ///
+  /// data : the user data to attach to surface
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or CAIRO_STATUS_NO_MEMORY if a slot could not be allocated for the user data.
+  ///
+  /// Since 1.0
+  pub fn set_user_data<T: 'static>(&mut self, key: &'static super::user_data::Key, data: T) -> super::Status {
+    unsafe {
+      let foreign_result = super::attach_user_data(self.opaque, ffi::cairo_surface_set_user_data, key, Some(data));
+      return foreign_result;
+    }
+  }
+
+  /// Remove the user data attached to surface with key, dropping it.
+  ///
+  /// surface : a surface::Surface
+  ///
+  /// key : the address of the user_data::Key the user data was attached to
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS or the error status of surface.
+  ///
+  /// Since 1.0
+  pub fn remove_user_data(&mut self, key: &'static super::user_data::Key) -> super::Status {
+    unsafe {
+      let foreign_result = super::attach_user_data::<()>(self.opaque, ffi::cairo_surface_set_user_data, key, None);
+      return foreign_result;
+    }
+  }
+
+  /// Return user data previously attached to surface using the specified key. If no user data has been attached with the given key, or it is not a T, this function returns None. The user data is cloned, since other references to the object may replace or remove it.
+  ///
+  /// surface : a surface::Surface
+  ///
+  /// key : the address of the user_data::Key the user data was attached to
+  ///
+  /// Returns : a clone of the user data previously attached or None.
+  ///
+  /// Since 1.0
+  pub fn get_user_data<T: Clone + 'static>(&mut self, key: &'static super::user_data::Key) -> Option<T> {
+    unsafe {
+      let foreign_result = super::find_user_data(self.opaque, ffi::cairo_surface_get_user_data, key);
       return foreign_result;
     }
   }
@@ -482,7 +793,7 @@ impl Surface {
   /// Since 1.6
   pub fn copy_page(&mut self) {
     unsafe {
-      cairo_surface_copy_page(self.opaque);
+      ffi::cairo_surface_copy_page(self.opaque);
     }
   }
 
@@ -495,7 +806,7 @@ impl Surface {
   /// Since 1.6
   pub fn show_page(&mut self) {
     unsafe {
-      cairo_surface_show_page(self.opaque);
+      ffi::cairo_surface_show_page(self.opaque);
     }
   }
 
@@ -512,7 +823,7 @@ impl Surface {
   /// Since 1.0
   pub fn image(format: format::Format, width: i32, height: i32) -> Surface {
     unsafe {
-      let foreign_result = cairo_image_surface_create(format, width, height);
+      let foreign_result = Surface { opaque: ffi::cairo_image_surface_create(format as ffi::cairo_format_t, width, height) };
       return foreign_result;
     }
   }
@@ -526,7 +837,7 @@ impl Surface {
   /// Since 1.2
   pub fn get_format(&mut self) -> format::Format {
     unsafe {
-      let foreign_result = cairo_image_surface_get_format(self.opaque);
+      let foreign_result = std::mem::transmute(ffi::cairo_image_surface_get_format(self.opaque));
       return foreign_result;
     }
   }
@@ -540,7 +851,7 @@ impl Surface {
   /// Since 1.0
   pub fn get_width(&mut self) -> i32 {
     unsafe {
-      let foreign_result = cairo_image_surface_get_width(self.opaque);
+      let foreign_result = ffi::cairo_image_surface_get_width(self.opaque);
       return foreign_result;
     }
   }
@@ -554,7 +865,7 @@ impl Surface {
   /// Since 1.0
   pub fn get_height(&mut self) -> i32 {
     unsafe {
-      let foreign_result = cairo_image_surface_get_height(self.opaque);
+      let foreign_result = ffi::cairo_image_surface_get_height(self.opaque);
       return foreign_result;
     }
   }
@@ -568,11 +879,31 @@ impl Surface {
   /// Since 1.2
   pub fn get_stride(&mut self) -> i32 {
     unsafe {
-      let foreign_result = cairo_image_surface_get_stride(self.opaque);
+      let foreign_result = ffi::cairo_image_surface_get_stride(self.opaque);
       return foreign_result;
     }
   }
 
+  /// Get a pointer to the data of the image surface, for direct inspection or modification.
+  ///
+  /// A call to cairo_surface_flush() is required before accessing the pixel data to ensure that all pending drawing operations are finished. A call to cairo_surface_mark_dirty() is required after the data is modified.
+  ///
+  /// surface : a cairo_image_surface_t
+  ///
+  /// Returns : the data of the image surface, stride times height bytes long, or an empty slice if surface is not an image surface, or if cairo_surface_finish() has been called.
+  ///
+  /// Since 1.2
+  pub fn get_data<'a>(&'a mut self) -> &'a mut [u8] {
+    unsafe {
+      let foreign_result = ffi::cairo_image_surface_get_data(self.opaque);
+      if foreign_result.is_null() {
+        return std::mem::transmute(std::raw::Slice { data: foreign_result as *u8, len: 0 });
+      }
+      let len = self.get_stride() as uint * self.get_height() as uint;
+      return std::mem::transmute(std::raw::Slice { data: foreign_result as *u8, len: len });
+    }
+  }
+
   /// Creates a new image surface and initializes the contents to the given PNG file.
   ///
   /// filename : name of PNG file to load
@@ -580,10 +911,11 @@ impl Surface {
   /// Returns : a new surface::Surface initialized with the contents of the PNG file, or a "nil" surface if any error occurred. A nil surface can be checked for with cairo_surface_status(surface) which may return one of the following values: CAIRO_STATUS_NO_MEMORY CAIRO_STATUS_FILE_NOT_FOUND CAIRO_STATUS_READ_ERROR Alternatively, you can allow errors to propagate through the drawing operations and check the status on the context upon completion using cairo_status().
   ///
   /// Since 1.0
+  #[cfg(feature = "png")]
   pub fn png(filename: &str) -> Surface {
     unsafe {
       use std::c_str::ToCStr;
-      let foreign_result = cairo_image_surface_create_from_png(filename.to_c_str().unwrap());
+      let foreign_result = Surface { opaque: ffi::cairo_image_surface_create_from_png(filename.to_c_str().unwrap()) };
       return foreign_result;
     }
   }
@@ -597,10 +929,11 @@ impl Surface {
   /// Returns : CAIRO_STATUS_SUCCESS if the PNG file was written successfully. Otherwise, CAIRO_STATUS_NO_MEMORY if memory could not be allocated for the operation or CAIRO_STATUS_SURFACE_TYPE_MISMATCH if the surface does not have pixel contents, or CAIRO_STATUS_WRITE_ERROR if an I/O error occurs while attempting to write the file.
   ///
   /// Since 1.0
+  #[cfg(feature = "png")]
   pub fn to_png(&mut self, filename: &str) -> super::Status {
     unsafe {
       use std::c_str::ToCStr;
-      let foreign_result = cairo_surface_write_to_png(self.opaque, filename.to_c_str().unwrap());
+      let foreign_result = std::mem::transmute(ffi::cairo_surface_write_to_png(self.opaque, filename.to_c_str().unwrap()));
       return foreign_result;
     }
   }
@@ -622,10 +955,11 @@ impl Surface {
   /// Returns : a pointer to the newly created surface. The caller owns the surface and should call cairo_surface_destroy() when done with it. This function always returns a valid pointer, but it will return a pointer to a "nil" surface if an error such as out of memory occurs. You can use cairo_surface_status() to check for this.
   /// 
   /// Since 1.2
+  #[cfg(feature = "svg")]
   pub fn svg(filename: &str, width: f64, height: f64) -> Surface {
     unsafe {
       use std::c_str::ToCStr;
-      let foreign_result = cairo_svg_surface_create(filename.to_c_str().unwrap(), width, height);
+      let foreign_result = Surface { opaque: ffi::cairo_svg_surface_create(filename.to_c_str().unwrap(), width, height) };
       return foreign_result;
     }
   }
@@ -639,9 +973,10 @@ impl Surface {
   /// version : SVG version
   ///
   /// Since 1.2
+  #[cfg(feature = "svg")]
   pub fn restrict_to_svg_version(&mut self, version: SVGVersion) {
     unsafe {
-      cairo_svg_surface_restrict_to_version(self, version);
+      ffi::cairo_svg_surface_restrict_to_version(self.opaque, version as ffi::cairo_svg_version_t);
     }
   }
 
@@ -652,72 +987,186 @@ impl Surface {
   /// Returns : the string associated to given version.
   ///
   /// Since 1.2
+  #[cfg(feature = "svg")]
   pub fn svg_version_to_string(version: SVGVersion) -> std::c_str::CString {
     unsafe {
-      let foreign_result = cairo_svg_version_to_string(version);
+      let foreign_result = ffi::cairo_svg_version_to_string(version as ffi::cairo_svg_version_t);
       return std::c_str::CString::new(foreign_result, false);
     }
   }
-}
 
-extern {
-  fn cairo_surface_create_similar_image(format: format::Format, width: i32, height: i32) -> Surface;
-  fn cairo_surface_create_for_rectangle(x: f64, y: f64, width: f64, height: f64) -> Surface;
-  fn cairo_surface_status(self_value: *mut libc::c_void) -> super::Status;
-  fn cairo_surface_finish(self_value: *mut libc::c_void);
-  fn cairo_surface_flush(self_value: *mut libc::c_void);
-  fn cairo_surface_get_device(self_value: *mut libc::c_void) -> Device;
-  fn cairo_surface_get_font_options(self_value: *mut libc::c_void, options: *mut libc::c_void);
-  fn cairo_surface_get_content(self_value: *mut libc::c_void) -> content::Content;
-  fn cairo_surface_mark_dirty(self_value: *mut libc::c_void);
-  fn cairo_surface_mark_dirty_rectangle(self_value: *mut libc::c_void, x: f64, y: f64, width: f64, height: f64);
-  fn cairo_surface_set_device_offset(self_value: *mut libc::c_void, x_offset: f64, y_offset: f64);
-  fn cairo_surface_get_device_offset(self_value: *mut libc::c_void, x_offset: *mut f64, y_offset: *mut f64);
-  fn cairo_surface_set_fallback_resolution(self_value: *mut libc::c_void, x_pixels_per_inch: f64, y_pixels_per_inch: f64);
-  fn cairo_surface_get_fallback_resolution(self_value: *mut libc::c_void, x_pixels_per_inch: *mut f64, y_pixels_per_inch: *mut f64);
-  fn cairo_surface_get_type(self_value: *mut libc::c_void) -> surface_type::SurfaceType;
-  fn cairo_surface_get_reference_count(self_value: *mut libc::c_void) -> i32;
-  fn cairo_surface_copy_page(self_value: *mut libc::c_void);
-  fn cairo_surface_show_page(self_value: *mut libc::c_void);
-  fn cairo_image_surface_create(format: format::Format, width: i32, height: i32) -> Surface;
-  fn cairo_image_surface_get_format(self_value: *mut libc::c_void) -> format::Format;
-  fn cairo_image_surface_get_width(self_value: *mut libc::c_void) -> i32;
-  fn cairo_image_surface_get_height(self_value: *mut libc::c_void) -> i32;
-  fn cairo_image_surface_get_stride(self_value: *mut libc::c_void) -> i32;
-  fn cairo_image_surface_create_from_png(filename: *libc::c_char) -> Surface;
-  fn cairo_surface_write_to_png(self_value: *mut libc::c_void, filename: *libc::c_char) -> super::Status;
-  fn cairo_svg_surface_create(filename: *libc::c_char, width: f64, height: f64) -> Surface;
-  fn cairo_svg_surface_restrict_to_version(self_value: *mut Surface, version: SVGVersion);
-  fn cairo_svg_version_to_string(version: SVGVersion) -> *i8;
+  /// Creates a PDF surface of the specified size in points to be written to filename.
+  ///
+  /// The PDF surface backend recognizes the following MIME types for the data attached to a surface (see cairo_surface_set_mime_data()) when it is used as a source pattern for drawing on this surface: CAIRO_MIME_TYPE_JPEG, CAIRO_MIME_TYPE_JP2, CAIRO_MIME_TYPE_UNIQUE_ID and CAIRO_MIME_TYPE_JBIG2. If any of them is specified, the PDF backend emits an image with the content of MIME data (with the /DCTDecode or /JPXDecode filter) instead of a surface snapshot (anything else).
+  ///
+  /// filename : a filename for the PDF output (must be writable), or None to specify no output. This will generate a PDF surface that may be queried and used as a source, without generating a temporary file.
+  ///
+  /// width_in_points : width of the surface, in points (1 point == 1/72.0 inch)
+  ///
+  /// height_in_points : height of the surface, in points (1 point == 1/72.0 inch)
+  ///
+  /// Returns : a pointer to the newly created surface. The caller owns the surface and should call cairo_surface_destroy() when done with it. This function always returns a valid pointer, but it will return a pointer to a "nil" surface if an error such as out of memory occurs. You can use cairo_surface_status() to check for this.
+  ///
+  /// Since 1.2
+  #[cfg(feature = "pdf")]
+  pub fn pdf(filename: Option<&str>, width: f64, height: f64) -> Surface {
+    unsafe {
+      use std::c_str::ToCStr;
+      let filename = match filename {
+        Some(filename) => filename.to_c_str().unwrap(),
+        None => std::ptr::null()
+      };
+      let foreign_result = Surface { opaque: ffi::cairo_pdf_surface_create(filename, width, height) };
+      return foreign_result;
+    }
+  }
+
+  /// Creates a recording-surface which can be used to record all drawing operations at the highest level (that is, the level of paint, mask, stroke, fill and show_text_glyphs). The recording surface can then be "replayed" against any target surface by using it as a source to drawing operations.
+  ///
+  /// The recording phase of the recording surface is careful to snapshot all necessary objects (paths, patterns, etc.), in order to achieve accurate replay.
+  ///
+  /// content : the content of the recording surface
+  ///
+  /// extents : the extents to record in pixels, or None to record unbounded operations.
+  ///
+  /// Returns : a pointer to the newly created surface. The caller owns the surface and should call cairo_surface_destroy() when done with it.
+  ///
+  /// Since 1.10
+  pub fn recording(content: content::Content, extents: Option<super::Rectangle>) -> Surface {
+    unsafe {
+      let extents = match extents {
+        Some(ref rectangle) => rectangle as *super::Rectangle as *ffi::cairo_rectangle_t,
+        None => std::ptr::null()
+      };
+      let foreign_result = Surface { opaque: ffi::cairo_recording_surface_create(content as ffi::cairo_content_t, extents) };
+      return foreign_result;
+    }
+  }
+
+  /// Create a new surface that will emit its rendering through script.
+  ///
+  /// script : the script (output device)
+  ///
+  /// content : the content of the surface
+  ///
+  /// width : width in pixels
+  ///
+  /// height : height in pixels
+  ///
+  /// Returns : a pointer to the newly created surface. The caller owns the surface and should call cairo_surface_destroy() when done with it.
+  ///
+  /// Since 1.12
+  #[cfg(feature = "script")]
+  pub fn script(script: &mut typed_device::ScriptDevice, content: content::Content, width: f64, height: f64) -> Surface {
+    unsafe {
+      let foreign_result = Surface { opaque: ffi::cairo_script_surface_create(script.opaque, content as ffi::cairo_content_t, width, height) };
+      return foreign_result;
+    }
+  }
+
+  /// Create a proxy surface that will render to target and record the operations to device.
+  ///
+  /// script : the script (output device)
+  ///
+  /// target : a target surface to wrap
+  ///
+  /// Returns : a pointer to the newly created surface. The caller owns the surface and should call cairo_surface_destroy() when done with it.
+  ///
+  /// Since 1.12
+  #[cfg(feature = "script")]
+  pub fn script_for_target(script: &mut typed_device::ScriptDevice, target: &mut Surface) -> Surface {
+    unsafe {
+      let foreign_result = Surface { opaque: ffi::cairo_script_surface_create_for_target(script.opaque, target.opaque) };
+      return foreign_result;
+    }
+  }
+
+  /// Creates a tee surface, which replays every drawing operation onto the primary surface and all additional targets, see surface::tee::TeeSurface::new().
+  ///
+  /// primary : the surface receiving drawing operations first, and answering queries
+  ///
+  /// Returns : the newly created tee surface.
+  ///
+  /// Since 1.10
+  #[cfg(feature = "tee")]
+  pub fn tee(primary: &mut Surface) -> tee::TeeSurface {
+    tee::TeeSurface::new(primary)
+  }
+
+  /// Create a new surface that exists solely to watch another is doing, see surface::observer::ObserverSurface::new().
+  ///
+  /// target : an existing surface for which the observer will watch
+  ///
+  /// mode : sets the mode of operation (normal vs. record)
+  ///
+  /// Returns : the newly allocated observer surface.
+  ///
+  /// Since 1.12
+  pub fn observer(target: &mut Surface, mode: observer_mode::ObserverMode) -> observer::ObserverSurface {
+    observer::ObserverSurface::new(target, mode)
+  }
 }
 
+
 impl std::clone::Clone for Surface {
   fn clone(&self) -> Surface {
     unsafe {
-      let foreign_result = cairo_surface_reference(self.opaque as *libc::c_void);
+      let foreign_result = Surface { opaque: ffi::cairo_surface_reference(self.opaque) };
       return foreign_result;
     }
   }
 }
 
-extern {
-  fn cairo_surface_reference(self_value: *libc::c_void) -> Surface;
-}
 
 impl std::ops::Drop for Surface {
   fn drop(&mut self) {
     unsafe {
-      cairo_surface_destroy(self.opaque);
+      ffi::cairo_surface_destroy(self.opaque);
     }
   }
 }
 
-extern {
-  fn cairo_surface_destroy(self_value: *mut libc::c_void);
+
+/// Key of the stream attached to devices writing to a Rust stream.
+#[cfg(feature = "script")]
+static STREAM_KEY: ffi::cairo_user_data_key_t = ffi::cairo_user_data_key_t { unused: 0 };
+
+extern "C" fn write_stream<W: std::io::Writer>(closure: *mut libc::c_void, data: *u8, length: libc::c_uint) -> ffi::cairo_status_t {
+  unsafe {
+    let stream: &mut W = std::mem::transmute(closure);
+    let data: &[u8] = std::mem::transmute(std::raw::Slice { data: data, len: length as uint });
+    match stream.write(data) {
+      Ok(()) => super::Success as ffi::cairo_status_t,
+      Err(_) => super::WriteError as ffi::cairo_status_t
+    }
+  }
+}
+
+#[cfg(feature = "script")]
+extern "C" fn destroy_stream<W: std::io::Writer>(closure: *mut libc::c_void) {
+  unsafe {
+    let _stream: Box<W> = std::mem::transmute(closure);
+  }
+}
+
+extern "C" fn destroy_mime_data(closure: *mut libc::c_void) {
+  unsafe {
+    let _data: Box<Vec<u8>> = std::mem::transmute(closure);
+  }
 }
 
 pub mod content;
 pub mod device_type;
+pub mod device_guard;
+pub mod typed_device;
 pub mod surface_type;
 pub mod format;
+pub mod mime;
+pub mod observer;
+pub mod observer_mode;
+#[cfg(feature = "v1_14")]
+pub mod scaled;
+pub mod script_mode;
+#[cfg(feature = "tee")]
+pub mod tee;
 
@@ -0,0 +1,238 @@
+//! Receive notifications about the drawing operations on an observer surface
+
+use std;
+use libc;
+use ffi;
+use super::Surface;
+use super::observer_mode;
+
+/// A surface::observer::Callback is notified by an observer surface, see surface::observer::ObserverSurface, after a drawing operation has been performed on its target.
+///
+/// Plain functions and boxed closures taking the observer and the target surface implement this trait, so they can be passed to surface::observer::ObserverSurface::add_paint_callback() and the related functions directly.
+///
+/// The surfaces passed to the callback are only borrowed for the duration of the call. They can be cloned to keep them, except in a finish callback, which may run while the observer is being destroyed.
+///
+/// Since 1.12
+pub trait Callback {
+  /// Called after the operation has been applied to target.
+  ///
+  /// observer : the observer surface
+  ///
+  /// target : the target surface of the observer
+  fn call(&mut self, observer: &mut Surface, target: &mut Surface);
+}
+
+impl Callback for fn(&mut Surface, &mut Surface) {
+  fn call(&mut self, observer: &mut Surface, target: &mut Surface) {
+    (*self)(observer, target)
+  }
+}
+
+impl Callback for Box<FnMut(&mut Surface, &mut Surface) + 'static> {
+  fn call(&mut self, observer: &mut Surface, target: &mut Surface) {
+    self.call_mut((observer, target))
+  }
+}
+
+/// A surface::observer::ObserverSurface is an observer surface::Surface, as created by surface::Surface::observer(). It watches the drawing operations on its target, logging operations and times, and calls the callbacks added to it. The functions of observer surfaces are only available on this type, while the functions for all surfaces are reached through it.
+///
+/// Since 1.12
+pub struct ObserverSurface {
+  surface: Surface
+}
+
+impl ObserverSurface {
+  /// Create a new surface that exists solely to watch another is doing. In the process it will log operations and times, which are fast, which are slow, which are frequent, etc.
+  ///
+  /// The mode parameter can be set to either surface::observer_mode::Normal or surface::observer_mode::RecordOperations, to control whether or not the internal observer should record operations.
+  ///
+  /// target : an existing surface for which the observer will watch
+  ///
+  /// mode : sets the mode of operation (normal vs. record)
+  ///
+  /// Returns : a pointer to the newly allocated surface. The caller owns the surface and should call cairo_surface_destroy() when done with it.
+  ///
+  /// Since 1.12
+  pub fn new(target: &mut Surface, mode: observer_mode::ObserverMode) -> ObserverSurface {
+    unsafe {
+      let foreign_result = ObserverSurface { surface: Surface { opaque: ffi::cairo_surface_create_observer(target.opaque, mode as ffi::cairo_surface_observer_mode_t) } };
+      return foreign_result;
+    }
+  }
+
+  /// Adds a callback called after each paint operation on the observer surface.
+  ///
+  /// surface : an observer surface::Surface
+  ///
+  /// callback : the callback, which is owned by the surface and dropped when the surface is destroyed
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS, or the error status of surface
+  ///
+  /// Since 1.12
+  pub fn add_paint_callback<C: Callback + 'static>(&mut self, callback: C) -> super::super::Status {
+    self.add_observer_callback(ffi::cairo_surface_observer_add_paint_callback, box callback)
+  }
+
+  /// Adds a callback called after each mask operation on the observer surface.
+  ///
+  /// surface : an observer surface::Surface
+  ///
+  /// callback : the callback, which is owned by the surface and dropped when the surface is destroyed
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS, or the error status of surface
+  ///
+  /// Since 1.12
+  pub fn add_mask_callback<C: Callback + 'static>(&mut self, callback: C) -> super::super::Status {
+    self.add_observer_callback(ffi::cairo_surface_observer_add_mask_callback, box callback)
+  }
+
+  /// Adds a callback called after each fill operation on the observer surface.
+  ///
+  /// surface : an observer surface::Surface
+  ///
+  /// callback : the callback, which is owned by the surface and dropped when the surface is destroyed
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS, or the error status of surface
+  ///
+  /// Since 1.12
+  pub fn add_fill_callback<C: Callback + 'static>(&mut self, callback: C) -> super::super::Status {
+    self.add_observer_callback(ffi::cairo_surface_observer_add_fill_callback, box callback)
+  }
+
+  /// Adds a callback called after each stroke operation on the observer surface.
+  ///
+  /// surface : an observer surface::Surface
+  ///
+  /// callback : the callback, which is owned by the surface and dropped when the surface is destroyed
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS, or the error status of surface
+  ///
+  /// Since 1.12
+  pub fn add_stroke_callback<C: Callback + 'static>(&mut self, callback: C) -> super::super::Status {
+    self.add_observer_callback(ffi::cairo_surface_observer_add_stroke_callback, box callback)
+  }
+
+  /// Adds a callback called after each glyphs operation on the observer surface.
+  ///
+  /// surface : an observer surface::Surface
+  ///
+  /// callback : the callback, which is owned by the surface and dropped when the surface is destroyed
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS, or the error status of surface
+  ///
+  /// Since 1.12
+  pub fn add_glyphs_callback<C: Callback + 'static>(&mut self, callback: C) -> super::super::Status {
+    self.add_observer_callback(ffi::cairo_surface_observer_add_glyphs_callback, box callback)
+  }
+
+  /// Adds a callback called after each flush of the observer surface.
+  ///
+  /// surface : an observer surface::Surface
+  ///
+  /// callback : the callback, which is owned by the surface and dropped when the surface is destroyed
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS, or the error status of surface
+  ///
+  /// Since 1.12
+  pub fn add_flush_callback<C: Callback + 'static>(&mut self, callback: C) -> super::super::Status {
+    self.add_observer_callback(ffi::cairo_surface_observer_add_flush_callback, box callback)
+  }
+
+  /// Adds a callback called when the observer surface is finished.
+  ///
+  /// surface : an observer surface::Surface
+  ///
+  /// callback : the callback, which is owned by the surface and dropped when the surface is destroyed
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS, or the error status of surface
+  ///
+  /// Since 1.12
+  pub fn add_finish_callback<C: Callback + 'static>(&mut self, callback: C) -> super::super::Status {
+    self.add_observer_callback(ffi::cairo_surface_observer_add_finish_callback, box callback)
+  }
+
+  /// Registers callback with add, keeping the callback alive for as long as the surface with user data keyed by the callback itself.
+  fn add_observer_callback(&mut self, add: unsafe extern "C" fn(*mut ffi::cairo_surface_t, ffi::cairo_surface_observer_callback_t, *mut libc::c_void) -> ffi::cairo_status_t, callback: Box<Callback + 'static>) -> super::super::Status {
+    unsafe {
+      let data: *mut libc::c_void = std::mem::transmute(box callback);
+      let status: super::super::Status = std::mem::transmute(ffi::cairo_surface_set_user_data(self.surface.opaque, data as *ffi::cairo_user_data_key_t, data, destroy_observer_callback));
+      match status {
+        super::super::Success => {},
+        error => {
+          destroy_observer_callback(data);
+          return error;
+        }
+      }
+      let foreign_result = std::mem::transmute(add(self.surface.opaque, observer_callback, data));
+      return foreign_result;
+    }
+  }
+
+  /// Returns the total observed time.
+  ///
+  /// surface : an observer surface::Surface
+  ///
+  /// Returns : the elapsed time in nanoseconds
+  ///
+  /// Since 1.12
+  pub fn elapsed(&mut self) -> f64 {
+    unsafe {
+      let foreign_result = ffi::cairo_surface_observer_elapsed(self.surface.opaque);
+      return foreign_result;
+    }
+  }
+
+  /// Returns a report of the operations observed by the observer surface and the time spent in them.
+  ///
+  /// surface : an observer surface::Surface
+  ///
+  /// Returns : the report, or the error status reported by cairo while printing it.
+  ///
+  /// Since 1.12
+  pub fn print(&mut self) -> Result<String, super::super::error::Error> {
+    unsafe {
+      let mut stream = std::io::MemWriter::new();
+      let closure = &mut stream as *mut std::io::MemWriter as *mut libc::c_void;
+      let status: super::super::Status = std::mem::transmute(ffi::cairo_surface_observer_print(self.surface.opaque, super::write_stream::<std::io::MemWriter>, closure));
+      match status {
+        super::super::Success => return Ok(String::from_utf8_lossy(stream.unwrap().as_slice()).into_string()),
+        error => return Err(super::super::error::CairoError(error))
+      }
+    }
+  }
+
+  /// Returns the observer surface as a plain surface::Surface.
+  pub fn unwrap(self) -> Surface {
+    self.surface
+  }
+}
+
+impl std::ops::Deref<Surface> for ObserverSurface {
+  fn deref<'a>(&'a self) -> &'a Surface {
+    &self.surface
+  }
+}
+
+impl std::ops::DerefMut<Surface> for ObserverSurface {
+  fn deref_mut<'a>(&'a mut self) -> &'a mut Surface {
+    &mut self.surface
+  }
+}
+
+extern "C" fn observer_callback(observer: *mut libc::c_void, target: *mut libc::c_void, data: *mut libc::c_void) {
+  unsafe {
+    let callback: &mut Box<Callback + 'static> = std::mem::transmute(data);
+    // The finish callback runs from cairo_surface_destroy() when the observer has no references left, so the wrappers borrow the surfaces instead of referencing them and are never dropped.
+    let mut observer = Surface { opaque: observer };
+    let mut target = Surface { opaque: target };
+    callback.call(&mut observer, &mut target);
+    std::mem::forget(observer);
+    std::mem::forget(target);
+  }
+}
+
+extern "C" fn destroy_observer_callback(data: *mut libc::c_void) {
+  unsafe {
+    let _callback: Box<Box<Callback + 'static>> = std::mem::transmute(data);
+  }
+}
@@ -0,0 +1,13 @@
+//! Describe what an observer surface records
+
+/// surface::observer_mode::ObserverMode selects whether an observer surface also records the individual operations, so they can be shown by cairo_surface_observer_print().
+///
+/// Since 1.12
+#[repr(i32)]
+#[deriving(Clone, PartialEq, Show)]
+pub enum ObserverMode {
+  /// no recording is done, since 1.12
+  Normal = 0,
+  /// operations are recorded, since 1.12
+  RecordOperations = 1
+}
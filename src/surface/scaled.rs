@@ -0,0 +1,110 @@
+//! Draw in logical units on surfaces with more pixels than units
+
+use std;
+use super::Surface;
+use super::format;
+use super::super::error;
+
+/// The largest width or height of an image surface, in device pixels.
+static MAX_IMAGE_SIZE: f64 = 32767.0;
+
+/// A surface::scaled::ScaledSurface is an image surface::Surface for a display with scale device pixels per logical unit, such as 2 for most HiDPI displays.
+///
+/// The image has the logical size multiplied by the scale, and its device scale is set with cairo_surface_set_device_scale(), so drawing code works in logical units and gets crisp output. The scale and the conversions between logical units and device pixels are read back from the surface, so they stay correct when the device scale or offset is changed through the surface.
+///
+/// Since 1.14
+pub struct ScaledSurface {
+  surface: Surface,
+  width: i32,
+  height: i32
+}
+
+impl ScaledSurface {
+  /// Creates an image surface of the given logical size for scale device pixels per logical unit.
+  ///
+  /// format : format of pixels in the surface to create
+  ///
+  /// width : width of the surface, in logical units, which must be positive
+  ///
+  /// height : height of the surface, in logical units, which must be positive
+  ///
+  /// scale : the number of device pixels per logical unit, in both directions, which must be finite and positive
+  ///
+  /// Returns : the newly created surface, or CAIRO_STATUS_INVALID_MATRIX if scale is not finite and positive, or CAIRO_STATUS_INVALID_SIZE if the size is not positive or the image would be larger than 32767 pixels in either direction, or the error status of the image surface if cairo failed to create it.
+  pub fn new(format: format::Format, width: i32, height: i32, scale: f64) -> Result<ScaledSurface, error::Error> {
+    // A zero or NaN scale makes the device transformation singular, which cairo treats as a fatal error.
+    if !(scale.is_finite() && scale > 0.0) {
+      return Err(error::CairoError(super::super::InvalidMatrix));
+    }
+    if width <= 0 || height <= 0 {
+      return Err(error::CairoError(super::super::InvalidSize));
+    }
+    // The physical size is checked as a float, since casting a size beyond the i32 range is undefined.
+    let physical_width = (width as f64 * scale).ceil();
+    let physical_height = (height as f64 * scale).ceil();
+    if physical_width > MAX_IMAGE_SIZE || physical_height > MAX_IMAGE_SIZE {
+      return Err(error::CairoError(super::super::InvalidSize));
+    }
+    let mut surface = Surface::image(format, physical_width as i32, physical_height as i32);
+    match surface.status() {
+      super::super::Success => {},
+      error => return Err(error::CairoError(error))
+    }
+    surface.set_device_scale(scale, scale);
+    return Ok(ScaledSurface { surface: surface, width: width, height: height });
+  }
+
+  /// Returns the number of device pixels per logical unit in the X and Y direction, as currently set on the surface.
+  pub fn scale(&mut self) -> (f64, f64) {
+    self.surface.get_device_scale()
+  }
+
+  /// Returns the width and height of the surface in logical units, as passed to surface::scaled::ScaledSurface::new().
+  pub fn logical_size(&self) -> (i32, i32) {
+    (self.width, self.height)
+  }
+
+  /// Returns the width and height of the image in device pixels.
+  pub fn physical_size(&mut self) -> (i32, i32) {
+    (self.surface.get_width(), self.surface.get_height())
+  }
+
+  /// Converts a point in logical units to device pixels, using the current device scale and offset of the surface.
+  ///
+  /// x : the X coordinate in logical units
+  ///
+  /// y : the Y coordinate in logical units
+  pub fn to_physical(&mut self, x: f64, y: f64) -> (f64, f64) {
+    let (x_scale, y_scale) = self.surface.get_device_scale();
+    let (x_offset, y_offset) = self.surface.get_device_offset();
+    (x * x_scale + x_offset, y * y_scale + y_offset)
+  }
+
+  /// Converts a point in device pixels to logical units, using the current device scale and offset of the surface.
+  ///
+  /// x : the X coordinate in device pixels
+  ///
+  /// y : the Y coordinate in device pixels
+  pub fn to_logical(&mut self, x: f64, y: f64) -> (f64, f64) {
+    let (x_scale, y_scale) = self.surface.get_device_scale();
+    let (x_offset, y_offset) = self.surface.get_device_offset();
+    ((x - x_offset) / x_scale, (y - y_offset) / y_scale)
+  }
+
+  /// Returns the underlying image surface.
+  pub fn unwrap(self) -> Surface {
+    self.surface
+  }
+}
+
+impl std::ops::Deref<Surface> for ScaledSurface {
+  fn deref<'a>(&'a self) -> &'a Surface {
+    &self.surface
+  }
+}
+
+impl std::ops::DerefMut<Surface> for ScaledSurface {
+  fn deref_mut<'a>(&'a mut self) -> &'a mut Surface {
+    &mut self.surface
+  }
+}
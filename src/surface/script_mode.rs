@@ -0,0 +1,13 @@
+//! Describe the output format of script devices
+
+/// surface::script_mode::ScriptMode is used to select the output format of a script device, see cairo_script_set_mode().
+///
+/// Since 1.12
+#[repr(i32)]
+#[deriving(Clone, PartialEq, Show)]
+pub enum ScriptMode {
+  /// the output will be in readable text (default), since 1.12
+  Ascii = 0,
+  /// the output will use byte codes, since 1.12
+  Binary = 1
+}
@@ -0,0 +1,88 @@
+//! Replay drawing operations onto several surfaces at once
+
+use std;
+use libc;
+use ffi;
+use super::Surface;
+
+/// A surface::tee::TeeSurface is a surface::Surface of type surface::surface_type::Tee, as created by surface::Surface::tee(). It replays every drawing operation onto its primary surface and all added targets, so one drawing pass can produce several outputs. The functions of the tee backend are only available on this type, while the functions for all surfaces are reached through it.
+///
+/// Since 1.10
+pub struct TeeSurface {
+  surface: Surface
+}
+
+impl TeeSurface {
+  /// Creates a tee surface, which replays every drawing operation onto the primary surface and all additional targets. Reading from the tee surface, for example with cairo_surface_get_font_options() or when it is used as a source, reads from the primary surface.
+  ///
+  /// primary : the surface receiving drawing operations first, and answering queries
+  ///
+  /// Returns : the newly created tee surface. The caller owns the surface and should call cairo_surface_destroy() when done with it.
+  ///
+  /// Since 1.10
+  pub fn new(primary: &mut Surface) -> TeeSurface {
+    unsafe {
+      let foreign_result = TeeSurface { surface: Surface { opaque: ffi::cairo_tee_surface_create(primary.opaque) } };
+      return foreign_result;
+    }
+  }
+
+  /// Adds target to the surfaces the tee surface replays drawing operations onto. The tee surface holds a reference to target until it is removed or the tee surface is destroyed.
+  ///
+  /// surface : a tee surface::Surface
+  ///
+  /// target : the surface to add
+  ///
+  /// Since 1.10
+  pub fn add(&mut self, target: &mut Surface) {
+    unsafe {
+      ffi::cairo_tee_surface_add(self.surface.opaque, target.opaque);
+    }
+  }
+
+  /// Removes target from the surfaces of the tee surface. The primary surface can not be removed; trying to do so puts surface into an error state with CAIRO_STATUS_INVALID_INDEX.
+  ///
+  /// surface : a tee surface::Surface
+  ///
+  /// target : the surface to remove
+  ///
+  /// Since 1.10
+  pub fn remove(&mut self, target: &mut Surface) {
+    unsafe {
+      ffi::cairo_tee_surface_remove(self.surface.opaque, target.opaque);
+    }
+  }
+
+  /// Gets a surface of the tee surface by position. Index 0 is the primary surface, the additional targets follow in the order they were added.
+  ///
+  /// surface : a tee surface::Surface
+  ///
+  /// index : the position of the surface
+  ///
+  /// Returns : the surface at index, or a "nil" surface with status CAIRO_STATUS_INVALID_INDEX if there is no surface at index.
+  ///
+  /// Since 1.10
+  pub fn index(&mut self, index: uint) -> Surface {
+    unsafe {
+      let foreign_result = Surface { opaque: ffi::cairo_surface_reference(ffi::cairo_tee_surface_index(self.surface.opaque, index as libc::c_uint)) };
+      return foreign_result;
+    }
+  }
+
+  /// Returns the tee surface as a plain surface::Surface.
+  pub fn unwrap(self) -> Surface {
+    self.surface
+  }
+}
+
+impl std::ops::Deref<Surface> for TeeSurface {
+  fn deref<'a>(&'a self) -> &'a Surface {
+    &self.surface
+  }
+}
+
+impl std::ops::DerefMut<Surface> for TeeSurface {
+  fn deref_mut<'a>(&'a mut self) -> &'a mut Surface {
+    &mut self.surface
+  }
+}
@@ -0,0 +1,160 @@
+//! Convert devices to typed devices for a single backend
+
+#[cfg(feature = "script")]
+use std;
+#[cfg(feature = "script")]
+use libc;
+#[cfg(feature = "script")]
+use ffi;
+use super::Device;
+#[cfg(feature = "script")]
+use super::Surface;
+#[cfg(feature = "script")]
+use super::device_type;
+
+/// A surface::typed_device::TypedDevice wraps a surface::Device of a single surface::device_type::DeviceType, so functions specific to that backend are only available on devices of the matching type. See surface::Device::downcast().
+pub trait TypedDevice {
+  /// Converts device into the typed device.
+  ///
+  /// device : a surface::Device
+  ///
+  /// Returns : the typed device, or device itself if it is of another type.
+  fn from_device(device: Device) -> Result<Self, Device>;
+
+  /// Converts the typed device back into a surface::Device.
+  fn into_device(self) -> Device;
+}
+
+/// A surface::typed_device::ScriptDevice is a surface::Device of type surface::device_type::Script, as created by surface::Device::script(). The functions of the script backend are only available on this type, while the functions for all devices are reached through it.
+///
+/// Since 1.12
+#[cfg(feature = "script")]
+pub struct ScriptDevice {
+  device: Device
+}
+
+#[cfg(feature = "script")]
+impl ScriptDevice {
+  /// Creates a output device for emitting the script, used when creating the individual surfaces.
+  ///
+  /// filename : the name (path) of the file to write the script to
+  ///
+  /// Returns : the newly created device. The caller owns the device and should call cairo_device_destroy() when done with it. This function always returns a valid pointer, but it will return a pointer to a "nil" device if an error such as out of memory occurs. You can use cairo_device_status() to check for this.
+  ///
+  /// Since 1.12
+  pub fn new(filename: &str) -> ScriptDevice {
+    unsafe {
+      use std::c_str::ToCStr;
+      let foreign_result = ScriptDevice { device: Device { opaque: ffi::cairo_script_create(filename.to_c_str().unwrap()) } };
+      return foreign_result;
+    }
+  }
+
+  /// Creates a output device for emitting the script, used when creating the individual surfaces. The script is written to stream, which is owned by the device and dropped when the device is destroyed.
+  ///
+  /// stream : the writer receiving the script
+  ///
+  /// Returns : the newly created device, or the error status of the device if cairo failed to create it.
+  ///
+  /// Since 1.12
+  pub fn for_stream<W: std::io::Writer + 'static>(stream: W) -> Result<ScriptDevice, super::super::error::Error> {
+    unsafe {
+      let closure: *mut libc::c_void = std::mem::transmute(box stream);
+      let foreign_result = ScriptDevice { device: Device { opaque: ffi::cairo_script_create_for_stream(super::write_stream::<W>, closure) } };
+      let status: super::super::Status = std::mem::transmute(ffi::cairo_device_set_user_data(foreign_result.device.opaque, &super::STREAM_KEY, closure, super::destroy_stream::<W>));
+      match status {
+        super::super::Success => return Ok(foreign_result),
+        error => {
+          // Without the key the stream would leak, so the device is closed and the stream dropped here.
+          ffi::cairo_device_finish(foreign_result.device.opaque);
+          let _stream: Box<W> = std::mem::transmute(closure);
+          return Err(super::super::error::CairoError(error));
+        }
+      }
+    }
+  }
+
+  /// Emit a string verbatim into the script.
+  ///
+  /// script : a script device
+  ///
+  /// comment : the string to emit
+  ///
+  /// Since 1.12
+  pub fn write_comment(&mut self, comment: &str) {
+    unsafe {
+      ffi::cairo_script_write_comment(self.device.opaque, comment.as_ptr() as *libc::c_char, comment.len() as libc::c_int);
+    }
+  }
+
+  /// Change the output mode of the script.
+  ///
+  /// script : a script device
+  ///
+  /// mode : the new mode
+  ///
+  /// Since 1.12
+  pub fn set_script_mode(&mut self, mode: super::script_mode::ScriptMode) {
+    unsafe {
+      ffi::cairo_script_set_mode(self.device.opaque, mode as ffi::cairo_script_mode_t);
+    }
+  }
+
+  /// Queries the script for its current output mode.
+  ///
+  /// script : a script device
+  ///
+  /// Returns : the current output mode of the script
+  ///
+  /// Since 1.12
+  pub fn get_script_mode(&mut self) -> super::script_mode::ScriptMode {
+    unsafe {
+      let foreign_result = std::mem::transmute(ffi::cairo_script_get_mode(self.device.opaque));
+      return foreign_result;
+    }
+  }
+
+  /// Converts the record operations in recording_surface into a script.
+  ///
+  /// script : the script (output device)
+  ///
+  /// recording_surface : the recording surface to replay
+  ///
+  /// Returns : CAIRO_STATUS_SUCCESS on successful completion or an error code.
+  ///
+  /// Since 1.12
+  pub fn script_from_recording_surface(&mut self, recording_surface: &mut Surface) -> super::super::Status {
+    unsafe {
+      let foreign_result = std::mem::transmute(ffi::cairo_script_from_recording_surface(self.device.opaque, recording_surface.opaque));
+      return foreign_result;
+    }
+  }
+}
+
+#[cfg(feature = "script")]
+impl TypedDevice for ScriptDevice {
+  fn from_device(mut device: Device) -> Result<ScriptDevice, Device> {
+    match device.get_type() {
+      device_type::Script => Ok(ScriptDevice { device: device }),
+      _ => Err(device)
+    }
+  }
+
+  fn into_device(self) -> Device {
+    self.device
+  }
+}
+
+#[cfg(feature = "script")]
+impl std::ops::Deref<Device> for ScriptDevice {
+  fn deref<'a>(&'a self) -> &'a Device {
+    &self.device
+  }
+}
+
+#[cfg(feature = "script")]
+impl std::ops::DerefMut<Device> for ScriptDevice {
+  fn deref_mut<'a>(&'a mut self) -> &'a mut Device {
+    &mut self.device
+  }
+}
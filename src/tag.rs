@@ -0,0 +1,319 @@
+//! Mark links, destinations and the document structure in PDF output
+
+#[cfg(feature = "v1_16")]
+use std;
+use std::fmt;
+#[cfg(feature = "v1_16")]
+use super::Cairo;
+
+/// tag::Tag names the structure started by cairo_tag_begin(). Link and Dest are interpreted by cairo, the other tags are the standard structure types of tagged PDF. Backends which do not support tags ignore them.
+///
+/// Since 1.16
+#[deriving(Clone, PartialEq, Show)]
+pub enum Tag {
+  /// a hyperlink, to a URI, a file or a destination in the document
+  Link,
+  /// a destination that links can refer to by name
+  Dest,
+  /// the whole document
+  Document,
+  /// a large division of the document
+  Part,
+  /// a self-contained article
+  Article,
+  /// a section of the document
+  Section,
+  /// a generic block-level grouping
+  Div,
+  /// a generic inline grouping
+  Span,
+  /// a block quotation
+  BlockQuote,
+  /// an inline quotation
+  Quote,
+  /// a caption of a table or figure
+  Caption,
+  /// a table of contents
+  TOC,
+  /// an item in a table of contents
+  TOCI,
+  /// an index
+  Index,
+  /// a footnote or endnote
+  Note,
+  /// a citation
+  Reference,
+  /// an entry in a bibliography
+  BibEntry,
+  /// computer code
+  Code,
+  /// a heading of unspecified level
+  H,
+  /// a heading of level 1
+  H1,
+  /// a heading of level 2
+  H2,
+  /// a heading of level 3
+  H3,
+  /// a heading of level 4
+  H4,
+  /// a heading of level 5
+  H5,
+  /// a heading of level 6
+  H6,
+  /// a paragraph
+  P,
+  /// a list
+  L,
+  /// an item of a list
+  LI,
+  /// the label of a list item
+  Lbl,
+  /// the body of a list item
+  LBody,
+  /// a table
+  Table,
+  /// a row of a table
+  TR,
+  /// a header cell of a table
+  TH,
+  /// a data cell of a table
+  TD,
+  /// the header rows of a table
+  THead,
+  /// the body rows of a table
+  TBody,
+  /// the footer rows of a table
+  TFoot,
+  /// a figure
+  Figure,
+  /// a mathematical formula
+  Formula
+}
+
+impl Tag {
+  /// Returns the name of the tag as passed to cairo_tag_begin().
+  pub fn name(&self) -> &'static str {
+    match *self {
+      Link => "Link",
+      Dest => "cairo.dest",
+      Document => "Document",
+      Part => "Part",
+      Article => "Art",
+      Section => "Sect",
+      Div => "Div",
+      Span => "Span",
+      BlockQuote => "BlockQuote",
+      Quote => "Quote",
+      Caption => "Caption",
+      TOC => "TOC",
+      TOCI => "TOCI",
+      Index => "Index",
+      Note => "Note",
+      Reference => "Reference",
+      BibEntry => "BibEntry",
+      Code => "Code",
+      H => "H",
+      H1 => "H1",
+      H2 => "H2",
+      H3 => "H3",
+      H4 => "H4",
+      H5 => "H5",
+      H6 => "H6",
+      P => "P",
+      L => "L",
+      LI => "LI",
+      Lbl => "Lbl",
+      LBody => "LBody",
+      Table => "Table",
+      TR => "TR",
+      TH => "TH",
+      TD => "TD",
+      THead => "THead",
+      TBody => "TBody",
+      TFoot => "TFoot",
+      Figure => "Figure",
+      Formula => "Formula"
+    }
+  }
+}
+
+/// tag::Attributes builds the attribute string passed to cairo_tag_begin(), of the form "key1=value1 key2=value2 ...". Strings are quoted and escaped, so they may contain any character.
+///
+/// A Link uses uri, file, dest, page and pos to name its target and rect for the areas that can be clicked. A Dest uses name, x, y and internal.
+///
+/// Since 1.16
+#[deriving(Clone, PartialEq)]
+pub struct Attributes {
+  attributes: Vec<String>
+}
+
+impl Attributes {
+  /// Creates an empty attribute list.
+  pub fn new() -> Attributes {
+    Attributes { attributes: Vec::new() }
+  }
+
+  /// Adds a string attribute and returns the list. The value is enclosed in single quotes, with single quotes and backslashes escaped by a backslash.
+  ///
+  /// key : the name of the attribute
+  ///
+  /// value : the value of the attribute
+  pub fn string(mut self, key: &str, value: &str) -> Attributes {
+    let mut quoted = String::from_str("'");
+    for c in value.chars() {
+      if c == '\'' || c == '\\' {
+        quoted.push_char('\\');
+      }
+      quoted.push_char(c);
+    }
+    quoted.push_char('\'');
+    self.attributes.push(format!("{}={}", key, quoted));
+    return self;
+  }
+
+  /// Adds an integer attribute and returns the list.
+  ///
+  /// key : the name of the attribute
+  ///
+  /// value : the value of the attribute
+  pub fn int(mut self, key: &str, value: i32) -> Attributes {
+    self.attributes.push(format!("{}={}", key, value));
+    return self;
+  }
+
+  /// Adds a floating point attribute and returns the list.
+  ///
+  /// key : the name of the attribute
+  ///
+  /// value : the value of the attribute
+  pub fn float(mut self, key: &str, value: f64) -> Attributes {
+    self.attributes.push(format!("{}={}", key, value));
+    return self;
+  }
+
+  /// Adds a boolean attribute and returns the list.
+  ///
+  /// key : the name of the attribute
+  ///
+  /// value : the value of the attribute
+  pub fn bool(mut self, key: &str, value: bool) -> Attributes {
+    self.attributes.push(format!("{}={}", key, value));
+    return self;
+  }
+
+  /// Adds an array attribute of numbers and returns the list.
+  ///
+  /// key : the name of the attribute
+  ///
+  /// values : the elements of the array
+  pub fn array(mut self, key: &str, values: &[f64]) -> Attributes {
+    let elements: Vec<String> = values.iter().map(|value| format!("{}", value)).collect();
+    self.attributes.push(format!("{}=[{}]", key, elements.connect(" ")));
+    return self;
+  }
+
+  /// Sets the URI a Link points to.
+  pub fn uri(self, uri: &str) -> Attributes {
+    self.string("uri", uri)
+  }
+
+  /// Sets the file a Link points to, together with page or dest for the position in that file.
+  pub fn file(self, file: &str) -> Attributes {
+    self.string("file", file)
+  }
+
+  /// Sets the name of the Dest a Link points to.
+  pub fn dest(self, dest: &str) -> Attributes {
+    self.string("dest", dest)
+  }
+
+  /// Sets the page a Link points to, starting at 1.
+  pub fn page(self, page: i32) -> Attributes {
+    self.int("page", page)
+  }
+
+  /// Sets the position on page a Link points to, in points from the top left of the page.
+  pub fn pos(self, x: f64, y: f64) -> Attributes {
+    self.array("pos", &[x, y])
+  }
+
+  /// Sets the area of a Link that can be clicked, in user space. Without it the area is the extents of the drawing operations inside the tag.
+  pub fn rect(self, x: f64, y: f64, width: f64, height: f64) -> Attributes {
+    self.array("rect", &[x, y, width, height])
+  }
+
+  /// Sets the name of a Dest, which links refer to with dest.
+  pub fn name(self, name: &str) -> Attributes {
+    self.string("name", name)
+  }
+
+  /// Sets the X coordinate of a Dest in user space. Without it, the X coordinate of the extents of the drawing operations inside the tag is used.
+  pub fn x(self, x: f64) -> Attributes {
+    self.float("x", x)
+  }
+
+  /// Sets the Y coordinate of a Dest in user space. Without it, the Y coordinate of the extents of the drawing operations inside the tag is used.
+  pub fn y(self, y: f64) -> Attributes {
+    self.float("y", y)
+  }
+
+  /// Sets whether a Dest is only used by links inside the document, so it is not exported for other documents.
+  pub fn internal(self, internal: bool) -> Attributes {
+    self.bool("internal", internal)
+  }
+}
+
+impl fmt::Show for Attributes {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.attributes.connect(" "))
+  }
+}
+
+/// A tag::TagGuard keeps a tag open on a Cairo, see Cairo::tag(). The tag is ended with cairo_tag_end() when the guard goes out of scope, so nested tags are always ended in the right order.
+///
+/// Since 1.16
+#[cfg(feature = "v1_16")]
+pub struct TagGuard<'a> {
+  cr: &'a mut Cairo,
+  tag: Tag
+}
+
+#[cfg(feature = "v1_16")]
+impl<'a> TagGuard<'a> {
+  /// Begins tag on cr.
+  ///
+  /// cr : a cairo context
+  ///
+  /// tag : tag name
+  ///
+  /// attributes : tag attributes
+  ///
+  /// Returns : the guard
+  pub fn begin(cr: &'a mut Cairo, tag: Tag, attributes: &Attributes) -> TagGuard<'a> {
+    cr.tag_begin(tag.clone(), attributes);
+    return TagGuard { cr: cr, tag: tag };
+  }
+}
+
+#[cfg(feature = "v1_16")]
+impl<'a> std::ops::Deref<Cairo> for TagGuard<'a> {
+  fn deref<'b>(&'b self) -> &'b Cairo {
+    &*self.cr
+  }
+}
+
+#[cfg(feature = "v1_16")]
+impl<'a> std::ops::DerefMut<Cairo> for TagGuard<'a> {
+  fn deref_mut<'b>(&'b mut self) -> &'b mut Cairo {
+    &mut *self.cr
+  }
+}
+
+#[cfg(feature = "v1_16")]
+#[unsafe_destructor]
+impl<'a> std::ops::Drop for TagGuard<'a> {
+  fn drop(&mut self) {
+    self.cr.tag_end(self.tag.clone());
+  }
+}
@@ -0,0 +1,12 @@
+//! Attach Rust values to cairo objects
+
+/// A user_data::Key identifies a value attached to a cairo object. Only its address is used, so each key must be declared as a static:
+///
+/// static WIDGET_ID: user_data::Key = user_data::Key { unused: 0 };
+///
+/// Since 1.0
+#[repr(C)]
+pub struct Key {
+  /// not used; there is no need to initialize it to anything else than 0
+  pub unused: i32
+}